@@ -0,0 +1,432 @@
+use super::{Symbol, UnsignedInteger};
+use std::fmt;
+
+//the sign nibble written when encoding, packed fields end in it and zoned fields carry it
+//in the zone of their last byte
+#[derive(Debug)]
+pub enum BcdSign {
+    Positive, //0xC, as in a signed COBOL field
+    Unsigned, //0xF, as in an unsigned COBOL field
+}
+
+impl Clone for BcdSign {
+    fn clone(&self) -> Self {
+        match self {
+            BcdSign::Positive => BcdSign::Positive,
+            BcdSign::Unsigned => BcdSign::Unsigned,
+        }
+    }
+}
+
+impl BcdSign {
+    fn nibble(&self) -> u8 {
+        match self {
+            BcdSign::Positive => 0xC,
+            BcdSign::Unsigned => 0xF,
+        }
+    }
+}
+
+//zones other than the last must be this, so digits read as EBCDIC '0' to '9'
+const ZONE: u8 = 0xF;
+
+#[derive(Debug)]
+pub enum BcdError {
+    Empty,
+    //positions are byte offsets into the field being decoded
+    InvalidDigit { position: usize, nibble: u8 },
+    InvalidZone { position: usize, nibble: u8 },
+    InvalidSign { nibble: u8 },
+    //a valid field holding a value below zero
+    Negative,
+    //the value has more digits than a field of width bytes can hold
+    Overflow { width: usize },
+    WidthMismatch { expected: usize, found: usize },
+}
+
+impl PartialEq for BcdError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (BcdError::Empty, BcdError::Empty) => true,
+            (
+                BcdError::InvalidDigit {
+                    position: a,
+                    nibble: x,
+                },
+                BcdError::InvalidDigit {
+                    position: b,
+                    nibble: y,
+                },
+            ) => a == b && x == y,
+            (
+                BcdError::InvalidZone {
+                    position: a,
+                    nibble: x,
+                },
+                BcdError::InvalidZone {
+                    position: b,
+                    nibble: y,
+                },
+            ) => a == b && x == y,
+            (BcdError::InvalidSign { nibble: x }, BcdError::InvalidSign { nibble: y }) => x == y,
+            (BcdError::Negative, BcdError::Negative) => true,
+            (BcdError::Overflow { width: a }, BcdError::Overflow { width: b }) => a == b,
+            (
+                BcdError::WidthMismatch {
+                    expected: a,
+                    found: x,
+                },
+                BcdError::WidthMismatch {
+                    expected: b,
+                    found: y,
+                },
+            ) => a == b && x == y,
+            _ => false,
+        }
+    }
+}
+
+impl Clone for BcdError {
+    fn clone(&self) -> Self {
+        match self {
+            BcdError::Empty => BcdError::Empty,
+            BcdError::InvalidDigit { position, nibble } => BcdError::InvalidDigit {
+                position: *position,
+                nibble: *nibble,
+            },
+            BcdError::InvalidZone { position, nibble } => BcdError::InvalidZone {
+                position: *position,
+                nibble: *nibble,
+            },
+            BcdError::InvalidSign { nibble } => BcdError::InvalidSign { nibble: *nibble },
+            BcdError::Negative => BcdError::Negative,
+            BcdError::Overflow { width } => BcdError::Overflow { width: *width },
+            BcdError::WidthMismatch { expected, found } => BcdError::WidthMismatch {
+                expected: *expected,
+                found: *found,
+            },
+        }
+    }
+}
+
+impl fmt::Display for BcdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BcdError::Empty => write!(f, "cannot decode decimal from empty field"),
+            BcdError::InvalidDigit { position, nibble } => {
+                write!(f, "invalid digit nibble {:#x} at byte {}", nibble, position)
+            }
+            BcdError::InvalidZone { position, nibble } => {
+                write!(f, "invalid zone nibble {:#x} at byte {}", nibble, position)
+            }
+            BcdError::InvalidSign { nibble } => write!(f, "invalid sign nibble {:#x}", nibble),
+            BcdError::Negative => write!(f, "field holds a negative value"),
+            BcdError::Overflow { width } => write!(f, "value does not fit in {} bytes", width),
+            BcdError::WidthMismatch { expected, found } => {
+                write!(f, "field is {} bytes long, expected {}", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BcdError {}
+
+//sign nibbles as IBM decimal instructions read them, true for negative
+fn is_negative(nibble: u8) -> Result<bool, BcdError> {
+    match nibble {
+        0xA | 0xC | 0xE | 0xF => Ok(false),
+        0xB | 0xD => Ok(true),
+        nibble => Err(BcdError::InvalidSign { nibble }),
+    }
+}
+
+//encoder and decoder for packed (COMP-3) and zoned decimal fields, by default fields
+//are as short as the value allows and are written with a positive sign
+#[derive(Debug)]
+pub struct BcdOptions {
+    width: Option<usize>,
+    sign: BcdSign,
+}
+
+impl Default for BcdOptions {
+    fn default() -> Self {
+        BcdOptions::new()
+    }
+}
+
+impl Clone for BcdOptions {
+    fn clone(&self) -> Self {
+        BcdOptions {
+            width: self.width,
+            sign: self.sign.clone(),
+        }
+    }
+}
+
+impl BcdOptions {
+    pub fn new() -> BcdOptions {
+        BcdOptions {
+            width: None,
+            sign: BcdSign::Positive,
+        }
+    }
+
+    //fixed field width in bytes, values are padded with leading zeros to fill it and
+    //fields of any other length are refused when decoding
+    pub fn width(mut self, bytes: usize) -> BcdOptions {
+        self.width = Some(bytes);
+        self
+    }
+
+    pub fn sign(mut self, sign: BcdSign) -> BcdOptions {
+        self.sign = sign;
+        self
+    }
+
+    //digit values most significant first, padded with zeros to the field's digit count
+    fn digits(&self, x: &UnsignedInteger, count: usize) -> Result<Vec<u8>, BcdError> {
+        let digits = x
+            .to_decimal_string()
+            .chars()
+            .map(|c| Symbol::from_char(&c).unwrap().value())
+            .collect::<Vec<_>>();
+        match self.width {
+            Some(width) if digits.len() > count => Err(BcdError::Overflow { width }),
+            _ => {
+                let mut padded = vec![0; count.saturating_sub(digits.len())];
+                padded.extend(digits);
+                Ok(padded)
+            }
+        }
+    }
+
+    fn check_width(&self, bytes: &[u8]) -> Result<(), BcdError> {
+        match self.width {
+            _ if bytes.is_empty() => Err(BcdError::Empty),
+            Some(expected) if expected != bytes.len() => Err(BcdError::WidthMismatch {
+                expected,
+                found: bytes.len(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    //two digits to a byte, with the sign in the last nibble, so 12345 is 12 34 5C
+    pub fn to_packed(&self, x: &UnsignedInteger) -> Result<Vec<u8>, BcdError> {
+        let width = self
+            .width
+            .unwrap_or_else(|| x.to_decimal_string().len() / 2 + 1);
+        if width == 0 {
+            return Err(BcdError::Overflow { width });
+        }
+        let mut nibbles = self.digits(x, 2 * width - 1)?;
+        nibbles.push(self.sign.nibble());
+        Ok(nibbles
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect())
+    }
+
+    //one digit to a byte under a zone of 0xF, with the sign in the last zone, so 123 is F1 F2 C3
+    pub fn to_zoned(&self, x: &UnsignedInteger) -> Result<Vec<u8>, BcdError> {
+        let width = self.width.unwrap_or_else(|| x.to_decimal_string().len());
+        let mut bytes = self
+            .digits(x, width)?
+            .iter()
+            .map(|digit| ZONE << 4 | digit)
+            .collect::<Vec<_>>();
+        if let Some(last) = bytes.last_mut() {
+            *last = self.sign.nibble() << 4 | (*last & 0xF);
+        }
+        Ok(bytes)
+    }
+
+    fn decode_nibbles(
+        &self,
+        digits: &[(usize, u8)],
+        sign: u8,
+    ) -> Result<UnsignedInteger, BcdError> {
+        let negative = is_negative(sign)?;
+        let symbols = digits
+            .iter()
+            .rev()
+            .map(|&(position, nibble)| {
+                Symbol::from_value(nibble).ok_or(BcdError::InvalidDigit { position, nibble })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let x = UnsignedInteger::raw(&symbols);
+        //negative zero is still zero
+        if negative && !x.is_zero() {
+            Err(BcdError::Negative)
+        } else {
+            Ok(x)
+        }
+    }
+
+    pub fn from_packed(&self, bytes: &[u8]) -> Result<UnsignedInteger, BcdError> {
+        self.check_width(bytes)?;
+        let mut nibbles = bytes
+            .iter()
+            .enumerate()
+            .flat_map(|(position, byte)| vec![(position, byte >> 4), (position, byte & 0xF)])
+            .collect::<Vec<_>>();
+        let (_, sign) = nibbles.pop().unwrap();
+        self.decode_nibbles(&nibbles, sign)
+    }
+
+    pub fn from_zoned(&self, bytes: &[u8]) -> Result<UnsignedInteger, BcdError> {
+        self.check_width(bytes)?;
+        let (last, rest) = bytes.split_last().unwrap();
+        if let Some(position) = rest.iter().position(|byte| byte >> 4 != ZONE) {
+            return Err(BcdError::InvalidZone {
+                position,
+                nibble: rest[position] >> 4,
+            });
+        }
+        let digits = bytes
+            .iter()
+            .enumerate()
+            .map(|(position, byte)| (position, byte & 0xF))
+            .collect::<Vec<_>>();
+        self.decode_nibbles(&digits, last >> 4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::big;
+
+    #[test]
+    fn test_to_packed() {
+        let options = BcdOptions::new();
+        assert_eq!(options.to_packed(&big("0")), Ok(vec![0x0C]));
+        assert_eq!(options.to_packed(&big("7")), Ok(vec![0x7C]));
+        assert_eq!(options.to_packed(&big("42")), Ok(vec![0x04, 0x2C]));
+        assert_eq!(options.to_packed(&big("1234")), Ok(vec![0x01, 0x23, 0x4C]));
+        assert_eq!(options.to_packed(&big("12345")), Ok(vec![0x12, 0x34, 0x5C]));
+        assert_eq!(
+            options
+                .clone()
+                .sign(BcdSign::Unsigned)
+                .to_packed(&big("12345")),
+            Ok(vec![0x12, 0x34, 0x5F])
+        );
+        assert_eq!(
+            options.clone().width(5).to_packed(&big("12345")),
+            Ok(vec![0x00, 0x00, 0x12, 0x34, 0x5C])
+        );
+        assert_eq!(
+            options.clone().width(2).to_packed(&big("12345")),
+            Err(BcdError::Overflow { width: 2 })
+        );
+        assert_eq!(
+            options.width(0).to_packed(&big("0")),
+            Err(BcdError::Overflow { width: 0 })
+        );
+    }
+    #[test]
+    fn test_from_packed() {
+        let options = BcdOptions::new();
+        assert_eq!(options.from_packed(&[0x12, 0x34, 0x5C]), Ok(big("12345")));
+        assert_eq!(options.from_packed(&[0x12, 0x34, 0x5F]), Ok(big("12345")));
+        assert_eq!(options.from_packed(&[0x00, 0x00, 0x4A]), Ok(big("4")));
+        assert_eq!(options.from_packed(&[0x00, 0x0D]), Ok(big("0")));
+        assert_eq!(
+            options.from_packed(&[0x12, 0x34, 0x5D]),
+            Err(BcdError::Negative)
+        );
+        assert_eq!(
+            options.from_packed(&[0x1A, 0x2C]),
+            Err(BcdError::InvalidDigit {
+                position: 0,
+                nibble: 0xA
+            })
+        );
+        assert_eq!(
+            options.from_packed(&[0x12, 0x34]),
+            Err(BcdError::InvalidSign { nibble: 0x4 })
+        );
+        assert_eq!(options.from_packed(&[]), Err(BcdError::Empty));
+        assert_eq!(
+            options.width(4).from_packed(&[0x12, 0x34, 0x5C]),
+            Err(BcdError::WidthMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+    #[test]
+    fn test_zoned() {
+        let options = BcdOptions::new();
+        assert_eq!(options.to_zoned(&big("0")), Ok(vec![0xC0]));
+        assert_eq!(options.to_zoned(&big("123")), Ok(vec![0xF1, 0xF2, 0xC3]));
+        assert_eq!(
+            options
+                .clone()
+                .sign(BcdSign::Unsigned)
+                .to_zoned(&big("123")),
+            Ok(vec![0xF1, 0xF2, 0xF3])
+        );
+        assert_eq!(
+            options.clone().width(5).to_zoned(&big("123")),
+            Ok(vec![0xF0, 0xF0, 0xF1, 0xF2, 0xC3])
+        );
+        assert_eq!(
+            options.clone().width(2).to_zoned(&big("123")),
+            Err(BcdError::Overflow { width: 2 })
+        );
+        assert_eq!(options.from_zoned(&[0xF1, 0xF2, 0xC3]), Ok(big("123")));
+        assert_eq!(options.from_zoned(&[0xF1, 0xF2, 0xF3]), Ok(big("123")));
+        assert_eq!(
+            options.from_zoned(&[0xF1, 0xF2, 0xD3]),
+            Err(BcdError::Negative)
+        );
+        assert_eq!(
+            options.from_zoned(&[0xF1, 0x32, 0xC3]),
+            Err(BcdError::InvalidZone {
+                position: 1,
+                nibble: 0x3
+            })
+        );
+        assert_eq!(
+            options.from_zoned(&[0xF1, 0xFB, 0xC3]),
+            Err(BcdError::InvalidDigit {
+                position: 1,
+                nibble: 0xB
+            })
+        );
+        assert_eq!(
+            options.from_zoned(&[0xF1, 0x23]),
+            Err(BcdError::InvalidSign { nibble: 0x2 })
+        );
+        assert_eq!(options.from_zoned(&[]), Err(BcdError::Empty));
+    }
+    #[test]
+    fn test_bcd_round_trip() {
+        [
+            "0",
+            "9",
+            "10",
+            "4221",
+            "999999999",
+            "1000000000",
+            &"1234567890".repeat(20),
+        ]
+        .iter()
+        .for_each(|x| {
+            let x = big(x);
+            [
+                BcdOptions::new(),
+                BcdOptions::new().sign(BcdSign::Unsigned).width(250),
+            ]
+            .iter()
+            .for_each(|options| {
+                let packed = options.to_packed(&x).unwrap();
+                assert_eq!(options.from_packed(&packed), Ok(x.clone()));
+                let zoned = options.to_zoned(&x).unwrap();
+                assert_eq!(options.from_zoned(&zoned), Ok(x.clone()));
+            });
+        });
+    }
+}
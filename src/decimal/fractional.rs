@@ -0,0 +1,381 @@
+use super::{limbs, Integer, ParseUnsignedIntegerError, UnsignedInteger};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+//value is coefficient * 10^-scale, so trailing fractional zeros are kept in the scale
+#[derive(Debug)]
+pub struct Decimal {
+    coefficient: Integer,
+    scale: usize,
+}
+
+impl Decimal {
+    pub fn new(coefficient: Integer, scale: usize) -> Decimal {
+        Decimal { coefficient, scale }
+    }
+
+    pub fn coefficient(&self) -> &Integer {
+        &self.coefficient
+    }
+
+    //number of digits after the decimal point
+    pub fn scale(&self) -> usize {
+        self.scale
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.coefficient.is_negative()
+    }
+
+    //increases the scale without changing the value
+    fn rescale(&self, scale: usize) -> Decimal {
+        Decimal {
+            coefficient: self.coefficient.shift_digits_up(scale - self.scale),
+            scale,
+        }
+    }
+
+    //trailing zeros of the coefficient that fall within the scale, read from the limbs with
+    //each whole zero limb nine zeros, a zero coefficient can drop the whole scale
+    fn droppable_zeros(&self) -> usize {
+        let limbs = limbs::significant(&self.coefficient.magnitude().limbs);
+        let whole = limbs.iter().take_while(|&&x| x == 0).count();
+        match limbs.get(whole) {
+            None => self.scale,
+            Some(&low) => {
+                let zeros = std::iter::successors(Some(low), |x| Some(x / 10))
+                    .take_while(|x| x % 10 == 0)
+                    .count();
+                std::cmp::min(whole * limbs::DIGITS + zeros, self.scale)
+            }
+        }
+    }
+
+    //brings both operands to the larger of the two scales
+    fn align(&self, other: &Self) -> (Decimal, Decimal) {
+        let scale = std::cmp::max(self.scale, other.scale);
+        (self.rescale(scale), other.rescale(scale))
+    }
+}
+
+impl From<Integer> for Decimal {
+    fn from(coefficient: Integer) -> Self {
+        Decimal::new(coefficient, 0)
+    }
+}
+
+impl From<UnsignedInteger> for Decimal {
+    fn from(coefficient: UnsignedInteger) -> Self {
+        Decimal::new(Integer::from(coefficient), 0)
+    }
+}
+
+//equality is by value, so 1.5 == 1.50
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = self.align(other);
+        a.coefficient == b.coefficient
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = self.align(other);
+        a.coefficient.cmp(&b.coefficient)
+    }
+}
+
+//hashes with the droppable zeros taken off, so values that are equal hash the same whatever
+//their scale
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let n = self.droppable_zeros();
+        let magnitude = limbs::shift_digits_down(&self.coefficient.magnitude().limbs, n);
+        self.is_negative().hash(state);
+        limbs::significant(&magnitude).hash(state);
+        (self.scale - n).hash(state);
+    }
+}
+
+impl Clone for Decimal {
+    fn clone(&self) -> Self {
+        Decimal {
+            coefficient: self.coefficient.clone(),
+            scale: self.scale,
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseUnsignedIntegerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.find('.') {
+            None => Integer::from_str(s).map(Decimal::from),
+            Some(point) => {
+                let (whole, fraction) = (&s[..point], &s[point + 1..]);
+                //a point needs digits on both sides of it
+                let misplaced_point = ParseUnsignedIntegerError::InvalidDigit {
+                    position: point,
+                    character: '.',
+                };
+                let unsigned_whole = whole.strip_prefix(&['+', '-'][..]).unwrap_or(whole);
+                if unsigned_whole.is_empty() {
+                    return Err(misplaced_point);
+                }
+                Integer::from_str(whole)
+                    .and_then(|_| {
+                        if fraction.is_empty() {
+                            Err(misplaced_point.clone())
+                        } else {
+                            UnsignedInteger::from_str(fraction).map_err(|e| e.shifted(point + 1))
+                        }
+                    })
+                    .and_then(|_| Integer::from_str(&format!("{}{}", whole, fraction)))
+                    .map(|coefficient| Decimal::new(coefficient, fraction.len()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.coefficient.magnitude().to_decimal_string();
+        let unsigned = if self.scale == 0 {
+            digits
+        } else {
+            //make sure there is at least one digit before the point
+            let padded = format!("{:0>width$}", digits, width = self.scale + 1);
+            let (whole, fraction) = padded.split_at(padded.len() - self.scale);
+            format!("{}.{}", whole, fraction)
+        };
+        f.pad_integral(!self.is_negative(), "", &unsigned)
+    }
+}
+
+use std::ops::Neg;
+impl Neg for Decimal {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Decimal::new(-self.coefficient, self.scale)
+    }
+}
+
+use std::ops::Add;
+impl Add for Decimal {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let (a, b) = self.align(&other);
+        Decimal::new(a.coefficient + b.coefficient, a.scale)
+    }
+}
+
+use std::ops::Sub;
+impl Sub for Decimal {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        let (a, b) = self.align(&other);
+        Decimal::new(a.coefficient - b.coefficient, a.scale)
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Decimal {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Decimal::new(
+            self.coefficient * other.coefficient,
+            self.scale + other.scale,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_from_str_round_trip() {
+        [
+            "0", "1", "-1", "0.5", "-0.001", "123.4500", "1.000", "-7555.25", "0.000",
+        ]
+        .iter()
+        .for_each(|x| assert_eq!(format!("{}", Decimal::from_str(x).unwrap()), *x));
+    }
+    #[test]
+    fn test_decimal_from_str_scale() {
+        let x = Decimal::from_str("123.4500").unwrap();
+        assert_eq!(x.scale(), 4);
+        assert_eq!(x.coefficient(), &Integer::from_str("1234500").unwrap());
+        let y = Decimal::from_str("-0.001").unwrap();
+        assert_eq!(y.scale(), 3);
+        assert_eq!(y.coefficient(), &Integer::from_str("-1").unwrap());
+    }
+    #[test]
+    fn test_decimal_from_str_normalises() {
+        assert_eq!(
+            format!("{}", Decimal::from_str("+007.10").unwrap()),
+            "7.10".to_string()
+        );
+        assert_eq!(
+            format!("{}", Decimal::from_str("-0.00").unwrap()),
+            "0.00".to_string()
+        );
+    }
+    #[test]
+    fn test_decimal_from_str_errors() {
+        assert_eq!(
+            Decimal::from_str(""),
+            Err(ParseUnsignedIntegerError::EmptyString)
+        );
+        assert_eq!(
+            Decimal::from_str("-"),
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '-'
+            })
+        );
+        [
+            ("1.", 1, '.'),
+            (".5", 0, '.'),
+            ("-.5", 1, '.'),
+            ("1.2.3", 3, '.'),
+            ("1,5", 1, ','),
+            ("1x.5", 1, 'x'),
+            ("abc", 0, 'a'),
+        ]
+        .iter()
+        .for_each(|(x, position, character)| {
+            assert_eq!(
+                Decimal::from_str(x),
+                Err(ParseUnsignedIntegerError::InvalidDigit {
+                    position: *position,
+                    character: *character
+                })
+            )
+        });
+        assert_eq!(
+            Decimal::from_str("-1.-5"),
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 3,
+                character: '-'
+            })
+        );
+    }
+    #[test]
+    fn test_decimal_eq_ignores_trailing_zeros() {
+        assert_eq!(
+            Decimal::from_str("1.5").unwrap(),
+            Decimal::from_str("1.500").unwrap()
+        );
+        assert_ne!(
+            Decimal::from_str("1.5").unwrap(),
+            Decimal::from_str("1.05").unwrap()
+        );
+    }
+    #[test]
+    fn test_decimal_ord() {
+        let cases = [
+            "-12.5", "-12.25", "-1", "-0.001", "0", "0.000", "0.001", "0.01", "1", "1.0", "1.5",
+            "1.50", "12.25", "100",
+        ]
+        .iter()
+        .map(|x| (Decimal::from_str(x).unwrap(), f64::from_str(x).unwrap()));
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .for_each(|((big_x, x), (big_y, y))| {
+                assert_eq!(big_x.partial_cmp(&big_y), x.partial_cmp(&y))
+            });
+    }
+    #[test]
+    fn test_decimal_hash_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+        let keys = ["1.5", "1.50", "1.500", "-0.00", "0", "-2.10", "-2.1"];
+        let mut hashed = HashMap::new();
+        let mut ordered = BTreeMap::new();
+        keys.iter().for_each(|x| {
+            hashed.insert(Decimal::from_str(x).unwrap(), *x);
+            ordered.insert(Decimal::from_str(x).unwrap(), *x);
+        });
+        assert_eq!(hashed.len(), 3);
+        assert_eq!(ordered.len(), 3);
+        assert_eq!(
+            hashed.get(&Decimal::from_str("1.5000").unwrap()),
+            Some(&"1.500")
+        );
+        assert_eq!(
+            ordered.keys().map(|x| format!("{}", x)).collect::<Vec<_>>(),
+            ["-2.10", "0.00", "1.5"]
+        );
+    }
+    #[test]
+    fn test_decimal_hash_across_limbs() {
+        use std::collections::hash_map::DefaultHasher;
+        let hash = |x: &Decimal| {
+            let mut hasher = DefaultHasher::new();
+            x.hash(&mut hasher);
+            hasher.finish()
+        };
+        //trailing zeros running into and past whole limbs, on both sides of the point
+        ["7", "70", "7000000000", "-12000000000000000000", "0"]
+            .iter()
+            .map(|x| Integer::from_str(x).unwrap())
+            .for_each(|x| {
+                let expected = hash(&Decimal::from(x.clone()));
+                (0..30).for_each(|scale| {
+                    let y = Decimal::new(x.shift_digits_up(scale), scale);
+                    assert_eq!(hash(&y), expected);
+                })
+            });
+        assert_ne!(
+            hash(&Decimal::from_str("1000000000").unwrap()),
+            hash(&Decimal::from_str("1.000000000").unwrap())
+        );
+    }
+    #[test]
+    fn test_decimal_arithmetic() {
+        let cases = [
+            ("1.5", "2.25", "3.75", "-0.75", "3.375"),
+            ("0.1", "0.2", "0.3", "-0.1", "0.02"),
+            ("123.4500", "-0.001", "123.4490", "123.4510", "-0.1234500"),
+            ("-7", "2.50", "-4.50", "-9.50", "-17.50"),
+            ("0.000", "5", "5.000", "-5.000", "0.000"),
+        ];
+        cases.iter().for_each(|(x, y, sum, difference, product)| {
+            let x = Decimal::from_str(x).unwrap();
+            let y = Decimal::from_str(y).unwrap();
+            assert_eq!(format!("{}", x.clone() + y.clone()), *sum);
+            assert_eq!(format!("{}", x.clone() - y.clone()), *difference);
+            assert_eq!(format!("{}", x * y), *product);
+        });
+    }
+    #[test]
+    fn test_decimal_neg() {
+        assert_eq!(
+            format!("{}", -Decimal::from_str("0.50").unwrap()),
+            "-0.50".to_string()
+        );
+    }
+    #[test]
+    fn test_decimal_display_formatter_flags() {
+        let x = Decimal::from_str("-0.50").unwrap();
+        assert_eq!(format!("{:>8}", x), "   -0.50".to_string());
+        assert_eq!(format!("{:08}", x), "-0000.50".to_string());
+        assert_eq!(
+            format!("{:+}", Decimal::from_str("1.5").unwrap()),
+            "+1.5".to_string()
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use super::UnsignedInteger;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum DigitGrouping {
+    Thousands,    //1,234,567
+    Indian,       //12,34,567, a group of three then groups of two
+    Every(usize), //fixed size groups, eg Every(4) gives 123,4567, zero means no grouping
+}
+
+impl Clone for DigitGrouping {
+    fn clone(&self) -> Self {
+        match self {
+            DigitGrouping::Thousands => DigitGrouping::Thousands,
+            DigitGrouping::Indian => DigitGrouping::Indian,
+            DigitGrouping::Every(n) => DigitGrouping::Every(*n),
+        }
+    }
+}
+
+impl DigitGrouping {
+    //size of the nth group, counting from the units end
+    fn size(&self, n: usize) -> Option<usize> {
+        match self {
+            DigitGrouping::Thousands => Some(3),
+            DigitGrouping::Indian if n == 0 => Some(3),
+            DigitGrouping::Indian => Some(2),
+            DigitGrouping::Every(0) => None,
+            DigitGrouping::Every(size) => Some(*size),
+        }
+    }
+
+    fn apply(&self, digits: &str, separator: char) -> String {
+        let digits = digits.chars().collect::<Vec<_>>();
+        let mut groups = Vec::new();
+        let mut end = digits.len();
+        while end > 0 {
+            let start = self
+                .size(groups.len())
+                .map_or(0, |size| end.saturating_sub(size));
+            groups.push(digits[start..end].iter().collect::<String>());
+            end = start;
+        }
+        groups.reverse(); //most significant group first
+        groups.join(&separator.to_string())
+    }
+}
+
+//displays an UnsignedInteger with separators between digit groups
+#[derive(Debug)]
+pub struct Grouped<'a> {
+    value: &'a UnsignedInteger,
+    grouping: DigitGrouping,
+    separator: char,
+}
+
+impl UnsignedInteger {
+    pub fn grouped(&self, grouping: DigitGrouping, separator: char) -> Grouped<'_> {
+        Grouped {
+            value: self,
+            grouping,
+            separator,
+        }
+    }
+}
+
+impl fmt::Display for Grouped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.value.to_decimal_string();
+        if !f.sign_aware_zero_pad() {
+            return f.pad_integral(true, "", &self.grouping.apply(&digits, self.separator));
+        }
+        //zero padding adds leading zeros to the digits before grouping, so the zeros are
+        //grouped too, eg {:010} gives 01,234,567, since a group boundary can fall where the
+        //width ends the result may be one character wider than asked
+        let sign = if f.sign_plus() { "+" } else { "" };
+        let width = f.width().unwrap_or(0);
+        let mut count = digits.len();
+        loop {
+            let padded = format!("{:0>width$}", digits, width = count);
+            let grouped = self.grouping.apply(&padded, self.separator);
+            if sign.len() + grouped.chars().count() >= width {
+                return write!(f, "{}{}", sign, grouped);
+            }
+            count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn grouped(x: &str, grouping: DigitGrouping, separator: char) -> String {
+        format!(
+            "{}",
+            UnsignedInteger::from_str(x)
+                .unwrap()
+                .grouped(grouping, separator)
+        )
+    }
+
+    #[test]
+    fn test_thousands() {
+        [
+            ("0", "0"),
+            ("12", "12"),
+            ("123", "123"),
+            ("1234", "1,234"),
+            ("123456", "123,456"),
+            ("1234567", "1,234,567"),
+            ("1000000000", "1,000,000,000"),
+        ]
+        .iter()
+        .for_each(|(x, expected)| assert_eq!(grouped(x, DigitGrouping::Thousands, ','), *expected));
+    }
+    #[test]
+    fn test_indian() {
+        [
+            ("0", "0"),
+            ("123", "123"),
+            ("1234", "1,234"),
+            ("12345", "12,345"),
+            ("1234567", "12,34,567"),
+            ("123456789", "12,34,56,789"),
+        ]
+        .iter()
+        .for_each(|(x, expected)| assert_eq!(grouped(x, DigitGrouping::Indian, ','), *expected));
+    }
+    #[test]
+    fn test_every() {
+        assert_eq!(grouped("1234567", DigitGrouping::Every(4), ' '), "123 4567");
+        assert_eq!(
+            grouped("12345678", DigitGrouping::Every(4), ' '),
+            "1234 5678"
+        );
+        assert_eq!(
+            grouped("1234567", DigitGrouping::Every(1), '-'),
+            "1-2-3-4-5-6-7"
+        );
+        assert_eq!(grouped("1234567", DigitGrouping::Every(0), ','), "1234567");
+    }
+    #[test]
+    fn test_custom_separators() {
+        assert_eq!(
+            grouped("1234567", DigitGrouping::Thousands, '.'),
+            "1.234.567"
+        );
+        assert_eq!(
+            grouped("1234567", DigitGrouping::Thousands, '\u{202F}'),
+            "1\u{202F}234\u{202F}567"
+        );
+        assert_eq!(
+            grouped("1234567", DigitGrouping::Thousands, '\''),
+            "1'234'567"
+        );
+    }
+    #[test]
+    fn test_grouped_formatter_flags() {
+        let x = UnsignedInteger::from_str("1234567").unwrap();
+        assert_eq!(
+            format!("{:>12}", x.grouped(DigitGrouping::Thousands, ',')),
+            "   1,234,567"
+        );
+        assert_eq!(
+            format!("{:+}", x.grouped(DigitGrouping::Indian, ',')),
+            "+12,34,567"
+        );
+    }
+    #[test]
+    fn test_grouped_zero_padding() {
+        let x = UnsignedInteger::from_str("1234567").unwrap();
+        let thousands = || x.grouped(DigitGrouping::Thousands, ',');
+        assert_eq!(format!("{:09}", thousands()), "1,234,567");
+        assert_eq!(format!("{:05}", thousands()), "1,234,567");
+        assert_eq!(format!("{:010}", thousands()), "01,234,567");
+        assert_eq!(format!("{:011}", thousands()), "001,234,567");
+        //no group starts with a separator, so this is one wider than asked
+        assert_eq!(format!("{:012}", thousands()), "0,001,234,567");
+        assert_eq!(format!("{:+011}", thousands()), "+01,234,567");
+        assert_eq!(
+            format!("{:012}", x.grouped(DigitGrouping::Indian, ',')),
+            "00,12,34,567"
+        );
+        assert_eq!(
+            format!(
+                "{:06}",
+                UnsignedInteger::from(0u8).grouped(DigitGrouping::Thousands, ' ')
+            ),
+            "00 000"
+        );
+    }
+}
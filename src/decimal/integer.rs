@@ -0,0 +1,381 @@
+use super::{DivideByZeroError, ParseUnsignedIntegerError, UnsignedInteger};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+#[derive(Debug)]
+enum Sign {
+    Negative,
+    Positive,
+}
+
+impl PartialEq for Sign {
+    fn eq(&self, other: &Self) -> bool {
+        match self {
+            Sign::Negative => matches!(other, Sign::Negative),
+            Sign::Positive => matches!(other, Sign::Positive),
+        }
+    }
+}
+
+impl Clone for Sign {
+    fn clone(&self) -> Self {
+        match self {
+            Sign::Negative => Sign::Negative,
+            Sign::Positive => Sign::Positive,
+        }
+    }
+}
+
+impl Sign {
+    fn flip(&self) -> Sign {
+        match self {
+            Sign::Negative => Sign::Positive,
+            Sign::Positive => Sign::Negative,
+        }
+    }
+
+    fn times(&self, other: &Sign) -> Sign {
+        if self == other {
+            Sign::Positive
+        } else {
+            Sign::Negative
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Integer {
+    sign: Sign,
+    magnitude: UnsignedInteger,
+}
+
+impl Integer {
+    fn new(sign: Sign, magnitude: UnsignedInteger) -> Integer {
+        if magnitude.is_zero() {
+            //there is only one zero, and it is not negative
+            Integer {
+                sign: Sign::Positive,
+                magnitude,
+            }
+        } else {
+            Integer { sign, magnitude }
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.sign == Sign::Negative
+    }
+
+    pub fn magnitude(&self) -> &UnsignedInteger {
+        &self.magnitude
+    }
+
+    //multiplies by 10^n, keeping the sign
+    pub(super) fn shift_digits_up(&self, n: usize) -> Integer {
+        Integer::new(self.sign.clone(), self.magnitude.shift_digits_up(n))
+    }
+}
+
+impl From<UnsignedInteger> for Integer {
+    fn from(magnitude: UnsignedInteger) -> Self {
+        Integer::new(Sign::Positive, magnitude)
+    }
+}
+
+impl PartialEq for Integer {
+    fn eq(&self, other: &Self) -> bool {
+        self.sign == other.sign && self.magnitude == other.magnitude
+    }
+}
+
+impl Eq for Integer {}
+
+impl PartialOrd for Integer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Integer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Hash for Integer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.is_negative().hash(state);
+        self.magnitude.hash(state);
+    }
+}
+
+impl Clone for Integer {
+    fn clone(&self) -> Self {
+        Integer {
+            sign: self.sign.clone(),
+            magnitude: self.magnitude.clone(),
+        }
+    }
+}
+
+impl FromStr for Integer {
+    type Err = ParseUnsignedIntegerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sign, digits) = if let Some(rest) = s.strip_prefix('-') {
+            (Sign::Negative, rest)
+        } else if let Some(rest) = s.strip_prefix('+') {
+            (Sign::Positive, rest)
+        } else {
+            (Sign::Positive, s)
+        };
+        //a lone sign leaves no digits, so it is the sign that is out of place
+        if digits.is_empty() && !s.is_empty() {
+            return Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: s.chars().next().unwrap(),
+            });
+        }
+        UnsignedInteger::from_str(digits)
+            .map(|magnitude| Integer::new(sign, magnitude))
+            .map_err(|e| e.shifted(s.len() - digits.len()))
+    }
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.is_negative(), "", &self.magnitude.to_decimal_string())
+    }
+}
+
+use std::ops::Neg;
+impl Neg for Integer {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Integer::new(self.sign.flip(), self.magnitude)
+    }
+}
+
+use std::ops::Add;
+impl Add for Integer {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        if self.sign == other.sign {
+            Integer::new(self.sign, self.magnitude + other.magnitude)
+        } else {
+            //opposite signs, so the larger magnitude wins
+            match self.magnitude.checked_sub(&other.magnitude) {
+                Some(difference) => Integer::new(self.sign, difference),
+                None => Integer::new(other.sign, other.magnitude - self.magnitude),
+            }
+        }
+    }
+}
+
+use std::ops::Sub;
+impl Sub for Integer {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Integer {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Integer::new(
+            self.sign.times(&other.sign),
+            self.magnitude * other.magnitude,
+        )
+    }
+}
+
+impl Integer {
+    //truncates towards zero and takes the remainder's sign from self, like Div and Rem
+    pub fn checked_div_rem(&self, other: &Self) -> Result<(Integer, Integer), DivideByZeroError> {
+        self.magnitude
+            .checked_div_rem(&other.magnitude)
+            .map(|(quotient, remainder)| {
+                (
+                    Integer::new(self.sign.times(&other.sign), quotient),
+                    Integer::new(self.sign.clone(), remainder),
+                )
+            })
+    }
+
+    pub fn checked_div(&self, other: &Self) -> Result<Integer, DivideByZeroError> {
+        self.checked_div_rem(other).map(|(q, _)| q)
+    }
+
+    pub fn checked_rem(&self, other: &Self) -> Result<Integer, DivideByZeroError> {
+        self.checked_div_rem(other).map(|(_, r)| r)
+    }
+}
+
+//division truncates towards zero, matching the primitive integer types
+use std::ops::Div;
+impl Div for Integer {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        Integer::new(
+            self.sign.times(&other.sign),
+            self.magnitude / other.magnitude,
+        )
+    }
+}
+
+//the remainder takes the sign of the dividend, matching the primitive integer types
+use std::ops::Rem;
+impl Rem for Integer {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        Integer::new(self.sign, self.magnitude % other.magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_from_str() {
+        ["0", "1", "-1", "10", "-10", "125", "-7555"]
+            .iter()
+            .for_each(|x| assert_eq!(format!("{}", Integer::from_str(x).unwrap()), *x));
+    }
+    #[test]
+    fn test_integer_from_str_plus() {
+        assert_eq!(Integer::from_str("+000010"), Integer::from_str("10"));
+    }
+    #[test]
+    fn test_integer_from_str_negative_zero() {
+        ["-0", "+0", "-00000"].iter().for_each(|x| {
+            let zero = Integer::from_str(x).unwrap();
+            assert!(!zero.is_negative());
+            assert_eq!(format!("{}", zero), "0".to_string());
+        });
+    }
+    #[test]
+    fn test_integer_from_str_errors() {
+        assert_eq!(
+            Integer::from_str(""),
+            Err(ParseUnsignedIntegerError::EmptyString)
+        );
+        ["-", "+"].iter().for_each(|x| {
+            assert_eq!(
+                Integer::from_str(x),
+                Err(ParseUnsignedIntegerError::UnexpectedSign {
+                    position: 0,
+                    character: x.chars().next().unwrap()
+                })
+            )
+        });
+        assert_eq!(
+            Integer::from_str("--1"),
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 1,
+                character: '-'
+            })
+        );
+        assert_eq!(
+            Integer::from_str("1-"),
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 1,
+                character: '-'
+            })
+        );
+        assert_eq!(
+            Integer::from_str("-12x"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 3,
+                character: 'x'
+            })
+        );
+    }
+    #[test]
+    fn test_integer_ord() {
+        let cases = ["0", "6", "-6", "12", "-15", "-613", "1024", "-4221", "7555"]
+            .iter()
+            .map(|x| (Integer::from_str(x).unwrap(), i32::from_str(x).unwrap()));
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .for_each(|((big_x, x), (big_y, y))| assert_eq!(big_x.cmp(&big_y), x.cmp(&y)));
+    }
+    #[test]
+    fn test_integer_neg() {
+        assert_eq!(
+            -Integer::from_str("15").unwrap(),
+            Integer::from_str("-15").unwrap()
+        );
+        assert_eq!(
+            -Integer::from_str("-15").unwrap(),
+            Integer::from_str("15").unwrap()
+        );
+        assert!(!(-Integer::from_str("0").unwrap()).is_negative());
+    }
+    #[test]
+    fn test_integer_arithmetic() {
+        let cases = [
+            "0", "6", "-6", "12", "-15", "50", "-75", "128", "-613", "1024", "-4221", "7555",
+        ]
+        .iter()
+        .map(|x| (Integer::from_str(x).unwrap(), i32::from_str(x).unwrap()));
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .flat_map(|((big_x, x), (big_y, y))| {
+                let mut results = vec![
+                    (big_x.clone() + big_y.clone(), x + y),
+                    (big_x.clone() - big_y.clone(), x - y),
+                    (big_x.clone() * big_y.clone(), x * y),
+                ];
+                if y != 0 {
+                    results.push((big_x.clone() / big_y.clone(), x / y));
+                    results.push((big_x % big_y, x % y));
+                }
+                results
+            })
+            .map(|(big, little)| (format!("{}", big), format!("{}", little)))
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_integer_checked_div_rem() {
+        let cases = ["0", "7", "-7", "2", "-2", "4221", "-7555"]
+            .iter()
+            .map(|x| (Integer::from_str(x).unwrap(), i32::from_str(x).unwrap()));
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .for_each(|((big_x, x), (big_y, y))| {
+                let expected = |r: Option<i32>| {
+                    r.map(|r| Integer::from_str(&r.to_string()).unwrap())
+                        .ok_or(DivideByZeroError)
+                };
+                assert_eq!(big_x.checked_div(&big_y), expected(x.checked_div(y)));
+                assert_eq!(big_x.checked_rem(&big_y), expected(x.checked_rem(y)));
+            });
+    }
+    #[test]
+    fn test_integer_display_formatter_flags() {
+        ["0", "-1", "7555", "-4221"].iter().for_each(|x| {
+            let big = Integer::from_str(x).unwrap();
+            let little = i32::from_str(x).unwrap();
+            assert_eq!(format!("{:>8}", big), format!("{:>8}", little));
+            assert_eq!(format!("{:08}", big), format!("{:08}", little));
+            assert_eq!(format!("{:+}", big), format!("{:+}", little));
+            assert_eq!(format!("{:_<8}", big), format!("{:_<8}", little));
+        });
+    }
+}
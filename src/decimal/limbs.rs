@@ -0,0 +1,307 @@
+//arithmetic on slices of limbs, stored least significant limb first
+//each limb holds DIGITS decimal digits, so it is always less than BASE
+use std::cmp::Ordering;
+
+pub(super) const DIGITS: usize = 9;
+pub(super) const BASE: u32 = 1_000_000_000;
+
+//powers of ten that fit in a single limb, used to shift by less than a whole limb
+pub(super) const POWERS_OF_TEN: [u32; DIGITS] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+];
+
+//removes zero limbs from the most significant end, so zero is the empty vector
+pub(super) fn normalise(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+pub(super) fn significant(limbs: &[u32]) -> &[u32] {
+    let len = limbs.iter().rposition(|&x| x != 0).map_or(0, |i| i + 1);
+    &limbs[..len]
+}
+
+pub(super) fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+    let a = significant(a);
+    let b = significant(b);
+    //more limbs is bigger, otherwise compare from the most significant limb down
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+//adds b into a, carrying in a single sweep
+pub(super) fn add_assign(a: &mut Vec<u32>, b: &[u32]) {
+    if a.len() < b.len() {
+        a.resize(b.len(), 0);
+    }
+    let mut carry = 0;
+    for (i, x) in a.iter_mut().enumerate() {
+        if i >= b.len() && carry == 0 {
+            break;
+        }
+        let sum = *x + b.get(i).cloned().unwrap_or(0) + carry;
+        if sum >= BASE {
+            *x = sum - BASE;
+            carry = 1;
+        } else {
+            *x = sum;
+            carry = 0;
+        }
+    }
+    if carry != 0 {
+        a.push(carry);
+    }
+}
+
+pub(super) fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = a.to_vec();
+    add_assign(&mut sum, b);
+    sum
+}
+
+//subtracts b from a in place, returning true if b was larger and the result wrapped
+pub(super) fn sub_assign(a: &mut Vec<u32>, b: &[u32]) -> bool {
+    if a.len() < b.len() {
+        a.resize(b.len(), 0);
+    }
+    let mut borrow = 0;
+    for (i, x) in a.iter_mut().enumerate() {
+        if i >= b.len() && borrow == 0 {
+            break;
+        }
+        let y = b.get(i).cloned().unwrap_or(0) + borrow;
+        if *x >= y {
+            *x -= y;
+            borrow = 0;
+        } else {
+            *x = *x + BASE - y;
+            borrow = 1;
+        }
+    }
+    borrow != 0
+}
+
+pub(super) fn mul_small_assign(a: &mut Vec<u32>, m: u32) {
+    let mut carry = 0u64;
+    for x in a.iter_mut() {
+        let product = *x as u64 * m as u64 + carry;
+        *x = (product % BASE as u64) as u32;
+        carry = product / BASE as u64;
+    }
+    while carry != 0 {
+        a.push((carry % BASE as u64) as u32);
+        carry /= BASE as u64;
+    }
+}
+
+pub(super) fn add_small_assign(a: &mut Vec<u32>, x: u32) {
+    add_assign(a, significant(&[x % BASE, x / BASE]));
+}
+
+//divides by a single machine word, returning the quotient and remainder
+pub(super) fn div_rem_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0; a.len()];
+    let mut remainder = 0u64;
+    for (q, x) in quotient.iter_mut().zip(a.iter()).rev() {
+        let current = remainder * BASE as u64 + *x as u64;
+        *q = (current / d as u64) as u32;
+        remainder = current % d as u64;
+    }
+    (normalise(quotient), remainder as u32)
+}
+
+//multiplies by 10^n by shifting in whole zero limbs then scaling by what is left
+pub(super) fn shift_digits_up(a: &[u32], n: usize) -> Vec<u32> {
+    let a = significant(a);
+    if a.is_empty() {
+        Vec::new()
+    } else {
+        let mut shifted = vec![0; n / DIGITS];
+        shifted.extend_from_slice(a);
+        mul_small_assign(&mut shifted, POWERS_OF_TEN[n % DIGITS]);
+        shifted
+    }
+}
+
+//divides by 10^n, dropping whole limbs then dividing by what is left
+pub(super) fn shift_digits_down(a: &[u32], n: usize) -> Vec<u32> {
+    let a = significant(a);
+    if n / DIGITS >= a.len() {
+        Vec::new()
+    } else {
+        let (shifted, _) = div_rem_small(&a[n / DIGITS..], POWERS_OF_TEN[n % DIGITS]);
+        shifted
+    }
+}
+
+//adds b into a starting at limb offset, a must be long enough to hold the result
+fn add_at(a: &mut [u32], b: &[u32], offset: usize) {
+    let mut carry = 0;
+    for (i, x) in a[offset..].iter_mut().enumerate() {
+        if i >= b.len() && carry == 0 {
+            break;
+        }
+        let sum = *x + b.get(i).cloned().unwrap_or(0) + carry;
+        if sum >= BASE {
+            *x = sum - BASE;
+            carry = 1;
+        } else {
+            *x = sum;
+            carry = 0;
+        }
+    }
+}
+
+fn schoolbook_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        if x != 0 {
+            let mut carry = 0u64;
+            for (j, &y) in b.iter().enumerate() {
+                let t = product[i + j] as u64 + x as u64 * y as u64 + carry;
+                product[i + j] = (t % BASE as u64) as u32;
+                carry = t / BASE as u64;
+            }
+            //nothing has been written this far up yet
+            product[i + b.len()] = carry as u32;
+        }
+    }
+    normalise(product)
+}
+
+//multiplies using Karatsuba once both operands are longer than threshold limbs
+pub(super) fn mul(a: &[u32], b: &[u32], threshold: usize) -> Vec<u32> {
+    let a = significant(a);
+    let b = significant(b);
+    if a.len() <= threshold || b.len() <= threshold {
+        schoolbook_mul(a, b)
+    } else {
+        let m = std::cmp::max(a.len(), b.len()) / 2;
+        let (a0, a1) = a.split_at(std::cmp::min(m, a.len()));
+        let (b0, b1) = b.split_at(std::cmp::min(m, b.len()));
+        let z0 = mul(a0, b0, threshold);
+        let z2 = mul(a1, b1, threshold);
+        let mut z1 = mul(&add(a0, a1), &add(b0, b1), threshold);
+        sub_assign(&mut z1, &z2);
+        sub_assign(&mut z1, &z0);
+        let z1 = normalise(z1);
+        let mut product = vec![0; a.len() + b.len() + 1];
+        add_at(&mut product, &z0, 0);
+        add_at(&mut product, &z1, m);
+        add_at(&mut product, &z2, 2 * m);
+        normalise(product)
+    }
+}
+
+//Montgomery reduction, t * BASE^-n mod m where m has n limbs and t is below m * BASE^n,
+//m_prime must satisfy m * m_prime = -1 mod BASE, so m must share no factor with BASE
+pub(super) fn montgomery_reduce(t: &[u32], m: &[u32], m_prime: u32) -> Vec<u32> {
+    let base = BASE as u64;
+    let n = m.len();
+    let mut t = t.to_vec();
+    t.resize(2 * n + 1, 0);
+    for i in 0..n {
+        //adding u * m clears limb i, so the sum stays a multiple of BASE^(i + 1)
+        let u = t[i] as u64 * m_prime as u64 % base;
+        let mut carry = 0u64;
+        for (j, &y) in m.iter().enumerate() {
+            let x = t[i + j] as u64 + u * y as u64 + carry;
+            t[i + j] = (x % base) as u32;
+            carry = x / base;
+        }
+        for x in t[i + n..].iter_mut() {
+            if carry == 0 {
+                break;
+            }
+            let sum = *x as u64 + carry;
+            *x = (sum % base) as u32;
+            carry = sum / base;
+        }
+    }
+    let mut reduced = normalise(t.split_off(n));
+    if cmp(&reduced, m) != Ordering::Less {
+        sub_assign(&mut reduced, m);
+    }
+    normalise(reduced)
+}
+
+//long division of u by a non zero v (Knuth, The Art of Computer Programming, 4.3.1 algorithm D)
+pub(super) fn div_rem(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let u = significant(u);
+    let v = significant(v);
+    if cmp(u, v) == Ordering::Less {
+        (Vec::new(), u.to_vec())
+    } else if v.len() == 1 {
+        let (quotient, remainder) = div_rem_small(u, v[0]);
+        (quotient, normalise(vec![remainder]))
+    } else {
+        let base = BASE as u64;
+        //scale both so the top limb of the divisor is at least half the base,
+        //which keeps each estimated quotient limb within two of the real one
+        let d = BASE / (v[v.len() - 1] + 1);
+        let mut un = u.to_vec();
+        mul_small_assign(&mut un, d);
+        un.resize(u.len() + 1, 0);
+        let mut vn = v.to_vec();
+        mul_small_assign(&mut vn, d);
+        let n = vn.len();
+        let m = un.len() - n - 1;
+        let mut quotient = vec![0; m + 1];
+        for j in (0..=m).rev() {
+            let top = un[j + n] as u64 * base + un[j + n - 1] as u64;
+            let mut qhat = top / vn[n - 1] as u64;
+            let mut rhat = top % vn[n - 1] as u64;
+            while qhat >= base || qhat * vn[n - 2] as u64 > rhat * base + un[j + n - 2] as u64 {
+                qhat -= 1;
+                rhat += vn[n - 1] as u64;
+                if rhat >= base {
+                    break;
+                }
+            }
+            //subtract qhat * vn from the current window of un
+            let mut borrow = 0i64;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let product = qhat * vn[i] as u64 + carry;
+                carry = product / base;
+                let t = un[i + j] as i64 - (product % base) as i64 - borrow;
+                if t < 0 {
+                    un[i + j] = (t + base as i64) as u32;
+                    borrow = 1;
+                } else {
+                    un[i + j] = t as u32;
+                    borrow = 0;
+                }
+            }
+            let t = un[j + n] as i64 - carry as i64 - borrow;
+            if t < 0 {
+                //qhat was one too big, so add the divisor back
+                un[j + n] = (t + base as i64) as u32;
+                qhat -= 1;
+                let mut carry = 0u64;
+                for i in 0..n {
+                    let sum = un[i + j] as u64 + vn[i] as u64 + carry;
+                    un[i + j] = (sum % base) as u32;
+                    carry = sum / base;
+                }
+                un[j + n] = ((un[j + n] as u64 + carry) % base) as u32;
+            } else {
+                un[j + n] = t as u32;
+            }
+            quotient[j] = qhat as u32;
+        }
+        let (remainder, _) = div_rem_small(&un[..n], d);
+        (normalise(quotient), remainder)
+    }
+}
@@ -1,3 +1,30 @@
+mod bcd;
+mod fractional;
+mod grouping;
+mod integer;
+mod limbs;
+mod modular;
+mod number_theory;
+mod parse_options;
+mod pow;
+mod primes;
+mod radix;
+mod roman;
+mod roots;
+mod scientific;
+#[cfg(feature = "serde")]
+mod serialisation;
+mod words;
+pub use bcd::{BcdError, BcdOptions, BcdSign};
+pub use fractional::Decimal;
+pub use grouping::{DigitGrouping, Grouped};
+pub use integer::Integer;
+pub use modular::Modulus;
+pub use parse_options::ParseOptions;
+pub use roman::{Roman, RomanMode, RomanNumeralError};
+pub use scientific::{Rounding, Scientific};
+pub use words::{Dialect, Scale, Words};
+
 #[derive(Debug)]
 enum Symbol {
     Zero,
@@ -12,6 +39,7 @@ enum Symbol {
     Nine,
 }
 
+#[allow(clippy::match_like_matches_macro)]
 impl PartialEq for Symbol {
     fn eq(&self, other: &Self) -> bool {
         match self {
@@ -76,7 +104,9 @@ impl Clone for Symbol {
     }
 }
 
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -94,7 +124,58 @@ impl fmt::Display for Symbol {
     }
 }
 
+impl Eq for Symbol {}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value().hash(state)
+    }
+}
+
 impl Symbol {
+    fn value(&self) -> u8 {
+        match self {
+            Symbol::Zero => 0,
+            Symbol::One => 1,
+            Symbol::Two => 2,
+            Symbol::Three => 3,
+            Symbol::Four => 4,
+            Symbol::Five => 5,
+            Symbol::Six => 6,
+            Symbol::Seven => 7,
+            Symbol::Eight => 8,
+            Symbol::Nine => 9,
+        }
+    }
+
+    fn from_value(x: u8) -> Option<Symbol> {
+        match x {
+            0 => Some(Symbol::Zero),
+            1 => Some(Symbol::One),
+            2 => Some(Symbol::Two),
+            3 => Some(Symbol::Three),
+            4 => Some(Symbol::Four),
+            5 => Some(Symbol::Five),
+            6 => Some(Symbol::Six),
+            7 => Some(Symbol::Seven),
+            8 => Some(Symbol::Eight),
+            9 => Some(Symbol::Nine),
+            _ => None,
+        }
+    }
+
     fn from_char(c: &char) -> Option<Symbol> {
         match c {
             '0' => Some(Symbol::Zero),
@@ -114,58 +195,68 @@ impl Symbol {
 
 #[derive(Debug)]
 pub struct UnsignedInteger {
-    symbols: Vec<Symbol>, //stored least significant digit first, ie units,tens,hundreds etc
+    limbs: Vec<u32>, //stored least significant limb first, each limb holding nine decimal digits
 }
 
 impl UnsignedInteger {
+    //builds from decimal symbols stored least significant digit first
     fn raw(ss: &[Symbol]) -> UnsignedInteger {
+        let limbs = ss
+            .chunks(limbs::DIGITS)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, s| acc * 10 + s.value() as u32)
+            })
+            .collect::<Vec<_>>();
+        UnsignedInteger::from_limbs(limbs)
+    }
+
+    fn from_limbs(limbs: Vec<u32>) -> UnsignedInteger {
         UnsignedInteger {
-            symbols: ss.to_vec(),
+            limbs: limbs::normalise(limbs),
         }
     }
-    fn digits(&self) -> usize {
-        self.symbols.len()
+
+    fn zero() -> UnsignedInteger {
+        UnsignedInteger { limbs: Vec::new() }
     }
 
-    fn pad_with_leading_zeros(&self, n: usize) -> UnsignedInteger {
-        let new_symbols = {
-            use std::iter;
-            let zeros = iter::repeat(Symbol::Zero).take(n).collect::<Vec<_>>();
-            self.symbols
-                .iter()
-                .chain(zeros.iter())
-                .cloned()
-                .collect::<Vec<_>>()
-        };
-        UnsignedInteger {
-            symbols: new_symbols,
-        }
+    fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&x| x == 0)
     }
 
-    fn strip_leading_zeros(&self) -> UnsignedInteger {
-        let new_symbols = {
-            let reversed = self.symbols.iter().rev().collect::<Vec<_>>();
-            reversed
-                .into_iter()
-                .skip_while(|&x| *x == Symbol::Zero)
-                .cloned()
-                .collect::<Vec<_>>()
-        };
-        if new_symbols.is_empty() {
-            UnsignedInteger {
-                symbols: [Symbol::Zero].to_vec(),
-            }
-        } else {
-            UnsignedInteger {
-                symbols: new_symbols,
-            }
-        }
+    //multiplies by 10^n
+    fn shift_digits_up(&self, n: usize) -> UnsignedInteger {
+        UnsignedInteger::from_limbs(limbs::shift_digits_up(&self.limbs, n))
     }
 }
 
+//comparisons ignore zero limbs at the most significant end
 impl PartialEq for UnsignedInteger {
     fn eq(&self, other: &Self) -> bool {
-        self.symbols.iter().eq(other.symbols.iter())
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for UnsignedInteger {}
+
+impl PartialOrd for UnsignedInteger {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UnsignedInteger {
+    fn cmp(&self, other: &Self) -> Ordering {
+        limbs::cmp(&self.limbs, &other.limbs)
+    }
+}
+
+impl Hash for UnsignedInteger {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        limbs::significant(&self.limbs).hash(state)
     }
 }
 
@@ -174,24 +265,124 @@ use std::str::FromStr;
 #[derive(Debug)]
 pub enum ParseUnsignedIntegerError {
     EmptyString,
-    NotANumber,
+    //a '+' or '-', positions are byte offsets into the string being parsed
+    UnexpectedSign { position: usize, character: char },
+    InvalidDigit { position: usize, character: char },
+}
+
+impl ParseUnsignedIntegerError {
+    fn unexpected(position: usize, character: char) -> ParseUnsignedIntegerError {
+        match character {
+            '+' | '-' => ParseUnsignedIntegerError::UnexpectedSign {
+                position,
+                character,
+            },
+            _ => ParseUnsignedIntegerError::InvalidDigit {
+                position,
+                character,
+            },
+        }
+    }
+
+    //moves the reported position along, for when only part of a string was parsed
+    fn shifted(self, offset: usize) -> ParseUnsignedIntegerError {
+        match self {
+            ParseUnsignedIntegerError::EmptyString => ParseUnsignedIntegerError::EmptyString,
+            ParseUnsignedIntegerError::UnexpectedSign {
+                position,
+                character,
+            } => ParseUnsignedIntegerError::UnexpectedSign {
+                position: position + offset,
+                character,
+            },
+            ParseUnsignedIntegerError::InvalidDigit {
+                position,
+                character,
+            } => ParseUnsignedIntegerError::InvalidDigit {
+                position: position + offset,
+                character,
+            },
+        }
+    }
 }
 
 impl PartialEq for ParseUnsignedIntegerError {
     fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ParseUnsignedIntegerError::EmptyString, ParseUnsignedIntegerError::EmptyString) => {
+                true
+            }
+            (
+                ParseUnsignedIntegerError::UnexpectedSign {
+                    position: a,
+                    character: x,
+                },
+                ParseUnsignedIntegerError::UnexpectedSign {
+                    position: b,
+                    character: y,
+                },
+            ) => a == b && x == y,
+            (
+                ParseUnsignedIntegerError::InvalidDigit {
+                    position: a,
+                    character: x,
+                },
+                ParseUnsignedIntegerError::InvalidDigit {
+                    position: b,
+                    character: y,
+                },
+            ) => a == b && x == y,
+            _ => false,
+        }
+    }
+}
+
+impl Clone for ParseUnsignedIntegerError {
+    fn clone(&self) -> Self {
         match self {
-            ParseUnsignedIntegerError::EmptyString => match other {
-                ParseUnsignedIntegerError::EmptyString => true,
-                _ => false,
+            ParseUnsignedIntegerError::EmptyString => ParseUnsignedIntegerError::EmptyString,
+            ParseUnsignedIntegerError::UnexpectedSign {
+                position,
+                character,
+            } => ParseUnsignedIntegerError::UnexpectedSign {
+                position: *position,
+                character: *character,
             },
-            ParseUnsignedIntegerError::NotANumber => match other {
-                ParseUnsignedIntegerError::NotANumber => true,
-                _ => false,
+            ParseUnsignedIntegerError::InvalidDigit {
+                position,
+                character,
+            } => ParseUnsignedIntegerError::InvalidDigit {
+                position: *position,
+                character: *character,
             },
         }
     }
 }
 
+impl fmt::Display for ParseUnsignedIntegerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseUnsignedIntegerError::EmptyString => {
+                write!(f, "cannot parse integer from empty string")
+            }
+            ParseUnsignedIntegerError::UnexpectedSign {
+                position,
+                character,
+            } => write!(
+                f,
+                "unexpected sign {:?} at byte {}, an unsigned integer has no sign",
+                character, position
+            ),
+            ParseUnsignedIntegerError::InvalidDigit {
+                position,
+                character,
+            } => write!(f, "invalid digit {:?} at byte {}", character, position),
+        }
+    }
+}
+
+impl std::error::Error for ParseUnsignedIntegerError {}
+
 impl FromStr for UnsignedInteger {
     type Err = ParseUnsignedIntegerError;
 
@@ -199,223 +390,388 @@ impl FromStr for UnsignedInteger {
         if s.is_empty() {
             Err(ParseUnsignedIntegerError::EmptyString)
         } else {
-            if s.chars().all(|c| c.is_digit(10)) {
-                let digits = s
-                    .chars()
-                    .skip_while(|c| *c == '0') //remove leading zeros
-                    .collect::<Vec<_>>();
+            s.char_indices()
+                .map(|(position, c)| {
+                    Symbol::from_char(&c)
+                        .ok_or_else(|| ParseUnsignedIntegerError::unexpected(position, c))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|mut symbols| {
+                    symbols.reverse(); //least significant digit first
+                    UnsignedInteger::raw(&symbols)
+                })
+        }
+    }
+}
+
+impl UnsignedInteger {
+    fn to_decimal_string(&self) -> String {
+        match limbs::significant(&self.limbs).split_last() {
+            None => "0".to_string(),
+            Some((top, rest)) => {
+                //only the most significant limb goes without its leading zeros
+                let mut digits = String::with_capacity((rest.len() + 1) * limbs::DIGITS);
+                digits.push_str(&top.to_string());
+                rest.iter().rev().for_each(|limb| {
+                    digits.push_str(&format!("{:09}", limb));
+                });
                 digits
-                    .iter()
-                    .rev()
-                    .map(|c| Symbol::from_char(&c))
-                    .collect::<Option<Vec<_>>>()
-                    .ok_or(ParseUnsignedIntegerError::NotANumber)
-                    .and_then(|s| {
-                        if s.is_empty() {
-                            Ok(UnsignedInteger::raw(&[Symbol::Zero]))
-                        } else {
-                            Ok(UnsignedInteger::raw(&s))
-                        }
-                    })
-            } else {
-                Err(ParseUnsignedIntegerError::NotANumber)
             }
         }
     }
 }
 
+//honours width, fill, alignment, zero padding and the + flag like the primitive integers
 impl fmt::Display for UnsignedInteger {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let x = self.symbols.iter().rev().cloned().collect::<Vec<_>>();
-
-        let y = x
-            .iter()
-            .cloned()
-            .skip_while(|x| *x == Symbol::Zero) //strip leading zeros
-            .fold(String::new(), |acc, x| format!("{}{}", acc, x));
-        if y.is_empty() {
-            write!(f, "0") //we've stripped all the zeros, so it must have been zero
-        } else {
-            write!(f, "{}", y)
-        }
+        f.pad_integral(true, "", &self.to_decimal_string())
     }
 }
 
 impl Clone for UnsignedInteger {
     fn clone(&self) -> Self {
         UnsignedInteger {
-            symbols: self.symbols.clone(),
+            limbs: self.limbs.clone(),
         }
     }
 }
 
-fn half_add_unit(a: &Symbol, b: &Symbol) -> (Symbol, Symbol) {
-    match a {
-        Symbol::Zero => match b {
-            Symbol::Zero => (Symbol::Zero, Symbol::Zero),
-            Symbol::One => (Symbol::One, Symbol::Zero),
-            Symbol::Two => (Symbol::Two, Symbol::Zero),
-            Symbol::Three => (Symbol::Three, Symbol::Zero),
-            Symbol::Four => (Symbol::Four, Symbol::Zero),
-            Symbol::Five => (Symbol::Five, Symbol::Zero),
-            Symbol::Six => (Symbol::Six, Symbol::Zero),
-            Symbol::Seven => (Symbol::Seven, Symbol::Zero),
-            Symbol::Eight => (Symbol::Eight, Symbol::Zero),
-            Symbol::Nine => (Symbol::Nine, Symbol::Zero),
-        },
-        Symbol::One => match b {
-            Symbol::Zero => (Symbol::One, Symbol::Zero),
-            Symbol::One => (Symbol::Two, Symbol::Zero),
-            Symbol::Two => (Symbol::Three, Symbol::Zero),
-            Symbol::Three => (Symbol::Four, Symbol::Zero),
-            Symbol::Four => (Symbol::Five, Symbol::Zero),
-            Symbol::Five => (Symbol::Six, Symbol::Zero),
-            Symbol::Six => (Symbol::Seven, Symbol::Zero),
-            Symbol::Seven => (Symbol::Eight, Symbol::Zero),
-            Symbol::Eight => (Symbol::Nine, Symbol::Zero),
-            Symbol::Nine => (Symbol::Zero, Symbol::One),
-        },
-        Symbol::Two => match b {
-            Symbol::Zero => (Symbol::Two, Symbol::Zero),
-            Symbol::One => (Symbol::Three, Symbol::Zero),
-            Symbol::Two => (Symbol::Four, Symbol::Zero),
-            Symbol::Three => (Symbol::Five, Symbol::Zero),
-            Symbol::Four => (Symbol::Six, Symbol::Zero),
-            Symbol::Five => (Symbol::Seven, Symbol::Zero),
-            Symbol::Six => (Symbol::Eight, Symbol::Zero),
-            Symbol::Seven => (Symbol::Nine, Symbol::Zero),
-            Symbol::Eight => (Symbol::Zero, Symbol::One),
-            Symbol::Nine => (Symbol::One, Symbol::One),
-        },
-        Symbol::Three => match b {
-            Symbol::Zero => (Symbol::Three, Symbol::Zero),
-            Symbol::One => (Symbol::Four, Symbol::Zero),
-            Symbol::Two => (Symbol::Five, Symbol::Zero),
-            Symbol::Three => (Symbol::Six, Symbol::Zero),
-            Symbol::Four => (Symbol::Seven, Symbol::Zero),
-            Symbol::Five => (Symbol::Eight, Symbol::Zero),
-            Symbol::Six => (Symbol::Nine, Symbol::Zero),
-            Symbol::Seven => (Symbol::Zero, Symbol::One),
-            Symbol::Eight => (Symbol::One, Symbol::One),
-            Symbol::Nine => (Symbol::Two, Symbol::One),
-        },
-        Symbol::Four => match b {
-            Symbol::Zero => (Symbol::Four, Symbol::Zero),
-            Symbol::One => (Symbol::Five, Symbol::Zero),
-            Symbol::Two => (Symbol::Six, Symbol::Zero),
-            Symbol::Three => (Symbol::Seven, Symbol::Zero),
-            Symbol::Four => (Symbol::Eight, Symbol::Zero),
-            Symbol::Five => (Symbol::Nine, Symbol::Zero),
-            Symbol::Six => (Symbol::Zero, Symbol::One),
-            Symbol::Seven => (Symbol::One, Symbol::One),
-            Symbol::Eight => (Symbol::Two, Symbol::One),
-            Symbol::Nine => (Symbol::Three, Symbol::One),
-        },
-        Symbol::Five => match b {
-            Symbol::Zero => (Symbol::Five, Symbol::Zero),
-            Symbol::One => (Symbol::Six, Symbol::Zero),
-            Symbol::Two => (Symbol::Seven, Symbol::Zero),
-            Symbol::Three => (Symbol::Eight, Symbol::Zero),
-            Symbol::Four => (Symbol::Nine, Symbol::Zero),
-            Symbol::Five => (Symbol::Zero, Symbol::One),
-            Symbol::Six => (Symbol::One, Symbol::One),
-            Symbol::Seven => (Symbol::Two, Symbol::One),
-            Symbol::Eight => (Symbol::Three, Symbol::One),
-            Symbol::Nine => (Symbol::Four, Symbol::One),
-        },
-        Symbol::Six => match b {
-            Symbol::Zero => (Symbol::Six, Symbol::Zero),
-            Symbol::One => (Symbol::Seven, Symbol::Zero),
-            Symbol::Two => (Symbol::Eight, Symbol::Zero),
-            Symbol::Three => (Symbol::Nine, Symbol::Zero),
-            Symbol::Four => (Symbol::Zero, Symbol::One),
-            Symbol::Five => (Symbol::One, Symbol::One),
-            Symbol::Six => (Symbol::Two, Symbol::One),
-            Symbol::Seven => (Symbol::Three, Symbol::One),
-            Symbol::Eight => (Symbol::Four, Symbol::One),
-            Symbol::Nine => (Symbol::Five, Symbol::One),
-        },
-        Symbol::Seven => match b {
-            Symbol::Zero => (Symbol::Seven, Symbol::Zero),
-            Symbol::One => (Symbol::Eight, Symbol::Zero),
-            Symbol::Two => (Symbol::Nine, Symbol::Zero),
-            Symbol::Three => (Symbol::Zero, Symbol::One),
-            Symbol::Four => (Symbol::One, Symbol::One),
-            Symbol::Five => (Symbol::Two, Symbol::One),
-            Symbol::Six => (Symbol::Three, Symbol::One),
-            Symbol::Seven => (Symbol::Four, Symbol::One),
-            Symbol::Eight => (Symbol::Five, Symbol::One),
-            Symbol::Nine => (Symbol::Six, Symbol::One),
-        },
-        Symbol::Eight => match b {
-            Symbol::Zero => (Symbol::Eight, Symbol::Zero),
-            Symbol::One => (Symbol::Nine, Symbol::Zero),
-            Symbol::Two => (Symbol::Zero, Symbol::One),
-            Symbol::Three => (Symbol::One, Symbol::One),
-            Symbol::Four => (Symbol::Two, Symbol::One),
-            Symbol::Five => (Symbol::Three, Symbol::One),
-            Symbol::Six => (Symbol::Four, Symbol::One),
-            Symbol::Seven => (Symbol::Five, Symbol::One),
-            Symbol::Eight => (Symbol::Six, Symbol::One),
-            Symbol::Nine => (Symbol::Seven, Symbol::One),
-        },
-        Symbol::Nine => match b {
-            Symbol::Zero => (Symbol::Nine, Symbol::Zero),
-            Symbol::One => (Symbol::Zero, Symbol::One),
-            Symbol::Two => (Symbol::One, Symbol::One),
-            Symbol::Three => (Symbol::Two, Symbol::One),
-            Symbol::Four => (Symbol::Three, Symbol::One),
-            Symbol::Five => (Symbol::Four, Symbol::One),
-            Symbol::Six => (Symbol::Five, Symbol::One),
-            Symbol::Seven => (Symbol::Six, Symbol::One),
-            Symbol::Eight => (Symbol::Seven, Symbol::One),
-            Symbol::Nine => (Symbol::Eight, Symbol::One),
-        },
-    }
-}
-
-use std::ops::Add;
+use std::ops::{Add, AddAssign};
+impl AddAssign<&UnsignedInteger> for UnsignedInteger {
+    fn add_assign(&mut self, other: &UnsignedInteger) {
+        limbs::add_assign(&mut self.limbs, &other.limbs);
+        self.limbs = limbs::normalise(std::mem::take(&mut self.limbs));
+    }
+}
+
+impl AddAssign for UnsignedInteger {
+    fn add_assign(&mut self, other: UnsignedInteger) {
+        *self += &other;
+    }
+}
+
+impl Add<&UnsignedInteger> for &UnsignedInteger {
+    type Output = UnsignedInteger;
+    fn add(self, other: &UnsignedInteger) -> UnsignedInteger {
+        UnsignedInteger::from_limbs(limbs::add(&self.limbs, &other.limbs))
+    }
+}
+
 impl Add for UnsignedInteger {
     type Output = Self;
-    fn add(self, other: Self) -> Self {
-        if self.digits() != other.digits() {
-            if self.digits() < other.digits() {
-                self.pad_with_leading_zeros(other.digits() - self.digits()) + other
-            } else {
-                self.clone() + other.pad_with_leading_zeros(self.digits() - other.digits())
-            }
+    fn add(mut self, other: Self) -> Self {
+        self += &other;
+        self
+    }
+}
+
+impl UnsignedInteger {
+    pub fn checked_sub(&self, other: &Self) -> Option<UnsignedInteger> {
+        let mut difference = self.limbs.clone();
+        if limbs::sub_assign(&mut difference, &other.limbs) {
+            None //other was larger than self
         } else {
-            let frame = self
-                .symbols
-                .iter()
-                .zip(other.symbols.iter())
-                .map(|(a, b)| half_add_unit(a, b))
-                .collect::<Vec<_>>();
-            let units = frame.iter().cloned().map(|(u, _)| u);
-            let tens = frame.iter().cloned().map(|(_, t)| t);
-            let done = tens.clone().all(|x| x == Symbol::Zero);
-            if done {
-                UnsignedInteger::raw(units.collect::<Vec<_>>().as_slice())
-            } else {
-                //add a leading zero to units
-                let new_units = {
-                    use std::iter;
-                    let once = iter::once(Symbol::Zero);
-                    units.chain(once).collect::<Vec<_>>()
-                };
-
-                //add a zero to the units of the tens to push it up by one
-                let new_tens = {
-                    use std::iter;
-                    let once = iter::once(Symbol::Zero);
-                    once.chain(tens).collect::<Vec<_>>()
-                };
-                UnsignedInteger::raw(&new_units) + UnsignedInteger::raw(&new_tens)
-            }
+            Some(UnsignedInteger::from_limbs(difference))
+        }
+    }
+
+    pub fn saturating_sub(&self, other: &Self) -> UnsignedInteger {
+        self.checked_sub(other)
+            .unwrap_or_else(UnsignedInteger::zero)
+    }
+}
+
+use std::ops::{Sub, SubAssign};
+impl SubAssign<&UnsignedInteger> for UnsignedInteger {
+    fn sub_assign(&mut self, other: &UnsignedInteger) {
+        if limbs::sub_assign(&mut self.limbs, &other.limbs) {
+            panic!("attempt to subtract with overflow");
         }
+        self.limbs = limbs::normalise(std::mem::take(&mut self.limbs));
     }
 }
 
+impl SubAssign for UnsignedInteger {
+    fn sub_assign(&mut self, other: UnsignedInteger) {
+        *self -= &other;
+    }
+}
+
+impl Sub<&UnsignedInteger> for &UnsignedInteger {
+    type Output = UnsignedInteger;
+    fn sub(self, other: &UnsignedInteger) -> UnsignedInteger {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Sub for UnsignedInteger {
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self {
+        self -= &other;
+        self
+    }
+}
+
+//schoolbook below this many digits, bench_mul_threshold found 180 to 720 within a percent of best
+pub(crate) const KARATSUBA_THRESHOLD: usize = 360;
+
+impl UnsignedInteger {
+    //multiplies using Karatsuba for operands longer than threshold digits
+    pub(crate) fn mul_with_threshold(&self, other: &Self, threshold: usize) -> UnsignedInteger {
+        //the limb code counts in limbs, rounding up so a threshold of one digit is one limb
+        let threshold = (std::cmp::max(threshold, 1) - 1) / limbs::DIGITS + 1;
+        UnsignedInteger::from_limbs(limbs::mul(&self.limbs, &other.limbs, threshold))
+    }
+}
+
+use std::ops::{Mul, MulAssign};
+impl MulAssign<&UnsignedInteger> for UnsignedInteger {
+    fn mul_assign(&mut self, other: &UnsignedInteger) {
+        *self = &*self * other;
+    }
+}
+
+impl MulAssign for UnsignedInteger {
+    fn mul_assign(&mut self, other: UnsignedInteger) {
+        *self *= &other;
+    }
+}
+
+impl Mul<&UnsignedInteger> for &UnsignedInteger {
+    type Output = UnsignedInteger;
+    fn mul(self, other: &UnsignedInteger) -> UnsignedInteger {
+        self.mul_with_threshold(other, KARATSUBA_THRESHOLD)
+    }
+}
+
+impl Mul for UnsignedInteger {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+#[derive(Debug)]
+pub struct DivideByZeroError;
+
+impl PartialEq for DivideByZeroError {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for DivideByZeroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "attempt to divide by zero")
+    }
+}
+
+impl std::error::Error for DivideByZeroError {}
+
+impl UnsignedInteger {
+    pub fn checked_div_rem(
+        &self,
+        other: &Self,
+    ) -> Result<(UnsignedInteger, UnsignedInteger), DivideByZeroError> {
+        if other.is_zero() {
+            Err(DivideByZeroError)
+        } else {
+            let (quotient, remainder) = limbs::div_rem(&self.limbs, &other.limbs);
+            Ok((
+                UnsignedInteger::from_limbs(quotient),
+                UnsignedInteger::from_limbs(remainder),
+            ))
+        }
+    }
+
+    pub fn checked_div(&self, other: &Self) -> Result<UnsignedInteger, DivideByZeroError> {
+        self.checked_div_rem(other).map(|(q, _)| q)
+    }
+
+    pub fn checked_rem(&self, other: &Self) -> Result<UnsignedInteger, DivideByZeroError> {
+        self.checked_div_rem(other).map(|(_, r)| r)
+    }
+
+    pub fn div_rem(&self, other: &Self) -> (UnsignedInteger, UnsignedInteger) {
+        self.checked_div_rem(other)
+            .expect("attempt to divide by zero")
+    }
+}
+
+use std::ops::{Div, DivAssign};
+impl DivAssign<&UnsignedInteger> for UnsignedInteger {
+    fn div_assign(&mut self, other: &UnsignedInteger) {
+        *self = &*self / other;
+    }
+}
+
+impl DivAssign for UnsignedInteger {
+    fn div_assign(&mut self, other: UnsignedInteger) {
+        *self /= &other;
+    }
+}
+
+impl Div<&UnsignedInteger> for &UnsignedInteger {
+    type Output = UnsignedInteger;
+    fn div(self, other: &UnsignedInteger) -> UnsignedInteger {
+        self.checked_div(other).expect("attempt to divide by zero")
+    }
+}
+
+impl Div for UnsignedInteger {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        &self / &other
+    }
+}
+
+use std::ops::{Rem, RemAssign};
+impl RemAssign<&UnsignedInteger> for UnsignedInteger {
+    fn rem_assign(&mut self, other: &UnsignedInteger) {
+        *self = &*self % other;
+    }
+}
+
+impl RemAssign for UnsignedInteger {
+    fn rem_assign(&mut self, other: UnsignedInteger) {
+        *self %= &other;
+    }
+}
+
+impl Rem<&UnsignedInteger> for &UnsignedInteger {
+    type Output = UnsignedInteger;
+    fn rem(self, other: &UnsignedInteger) -> UnsignedInteger {
+        self.checked_rem(other)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+impl Rem for UnsignedInteger {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        &self % &other
+    }
+}
+
+impl UnsignedInteger {
+    fn from_u128(mut x: u128) -> UnsignedInteger {
+        let mut limbs = Vec::new();
+        while x != 0 {
+            limbs.push((x % limbs::BASE as u128) as u32);
+            x /= limbs::BASE as u128;
+        }
+        UnsignedInteger::from_limbs(limbs)
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        limbs::significant(&self.limbs)
+            .iter()
+            .rev()
+            .try_fold(0u128, |acc, &limb| {
+                acc.checked_mul(limbs::BASE as u128)
+                    .and_then(|x| x.checked_add(limb as u128))
+            })
+    }
+}
+
+use std::convert::TryFrom;
+
+macro_rules! impl_from_unsigned_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for UnsignedInteger {
+                fn from(x: $t) -> Self {
+                    UnsignedInteger::from_u128(x as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned_primitive!(u8, u16, u32, u64, u128, usize);
+
+#[derive(Debug)]
+pub struct OverflowError;
+
+impl PartialEq for OverflowError {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value is too large for the target integer type")
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+macro_rules! impl_try_from_unsigned_integer {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<&UnsignedInteger> for $t {
+                type Error = OverflowError;
+                fn try_from(x: &UnsignedInteger) -> Result<Self, Self::Error> {
+                    x.to_u128()
+                        .and_then(|x| <$t>::try_from(x).ok())
+                        .ok_or(OverflowError)
+                }
+            }
+
+            impl TryFrom<UnsignedInteger> for $t {
+                type Error = OverflowError;
+                fn try_from(x: UnsignedInteger) -> Result<Self, Self::Error> {
+                    <$t>::try_from(&x)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_unsigned_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug)]
+pub struct NegativeValueError;
+
+impl PartialEq for NegativeValueError {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for NegativeValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an unsigned integer cannot hold a negative value")
+    }
+}
+
+impl std::error::Error for NegativeValueError {}
+
+macro_rules! impl_try_from_signed_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<$t> for UnsignedInteger {
+                type Error = NegativeValueError;
+                fn try_from(x: $t) -> Result<Self, Self::Error> {
+                    u128::try_from(x)
+                        .map(UnsignedInteger::from_u128)
+                        .map_err(|_| NegativeValueError)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_signed_primitive!(i8, i16, i32, i64, i128, isize);
+
+//shorthand for the large operands in the tests of the submodules
+#[cfg(test)]
+fn big(x: &str) -> UnsignedInteger {
+    UnsignedInteger::from_str(x).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -509,77 +865,110 @@ mod tests {
     fn test_interger_from_str_not_a_number() {
         assert_eq!(
             UnsignedInteger::from_str("Hello World"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 0,
+                character: 'H'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_minus_1() {
         assert_eq!(
             UnsignedInteger::from_str("-1"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '-'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_minus_10() {
         assert_eq!(
             UnsignedInteger::from_str("-10"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '-'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_plus_10() {
         assert_eq!(
             UnsignedInteger::from_str("+10"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '+'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_minus_000010() {
         assert_eq!(
             UnsignedInteger::from_str("-000010"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '-'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_plus_000010() {
         assert_eq!(
             UnsignedInteger::from_str("+000010"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '+'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_minus_0() {
         assert_eq!(
             UnsignedInteger::from_str("-0"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '-'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_plus_0() {
         assert_eq!(
             UnsignedInteger::from_str("+0"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '+'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_plus_00000() {
         assert_eq!(
             UnsignedInteger::from_str("+00000"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '+'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_dashes_centre() {
         assert_eq!(
             UnsignedInteger::from_str("125-12"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 3,
+                character: '-'
+            })
         );
     }
     #[test]
     fn test_interger_from_str_pluses_centre() {
         assert_eq!(
             UnsignedInteger::from_str("125+12"),
-            Err(ParseUnsignedIntegerError::NotANumber)
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 3,
+                character: '+'
+            })
         );
     }
 
@@ -636,4 +1025,659 @@ mod tests {
             assert_eq!(format!("{}",x+y),"1551324303876771241884343113259599609378653143111280111834187291909203932606583041853156452338126711".to_string());
         }
     }
+
+    #[test]
+    fn test_sub() {
+        let cases = [
+            "0", "6", "12", "15", "50", "75", "128", "613", "1024", "4221", "7555",
+        ]
+        .iter()
+        .map(|x| {
+            (
+                UnsignedInteger::from_str(x).unwrap(),
+                u32::from_str(x).unwrap(),
+            )
+        });
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .filter(|((_, x), (_, y))| x >= y)
+            .map(|((big_x, x), (big_y, y))| (big_x - big_y, x - y))
+            .map(|(big, little)| (format!("{}", big), format!("{}", little)))
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_sub_borrow_chain() {
+        let x = UnsignedInteger::from_str("1000000").unwrap();
+        let y = UnsignedInteger::from_str("1").unwrap();
+        assert_eq!(x - y, UnsignedInteger::from_str("999999").unwrap());
+    }
+    #[test]
+    fn test_sub_to_zero() {
+        let x = UnsignedInteger::from_str("4221").unwrap();
+        assert_eq!(x.clone() - x, UnsignedInteger::from_str("0").unwrap());
+    }
+    #[test]
+    fn test_sub_large() {
+        let x = UnsignedInteger::from_str("1551324303876771241884343113259599609378653143111280111834187291909203932606583041853156452338126711").unwrap();
+        let y = UnsignedInteger::from_str("6").unwrap();
+        assert_eq!(format!("{}",x-y),"1551324303876771241884343113259599609378653143111280111834187291909203932606583041853156452338126705".to_string());
+    }
+    #[test]
+    fn test_checked_sub() {
+        let x = UnsignedInteger::from_str("128").unwrap();
+        let y = UnsignedInteger::from_str("613").unwrap();
+        assert_eq!(x.checked_sub(&y), None);
+        assert_eq!(
+            y.checked_sub(&x),
+            Some(UnsignedInteger::from_str("485").unwrap())
+        );
+    }
+    #[test]
+    fn test_saturating_sub() {
+        let x = UnsignedInteger::from_str("128").unwrap();
+        let y = UnsignedInteger::from_str("613").unwrap();
+        assert_eq!(
+            x.saturating_sub(&y),
+            UnsignedInteger::from_str("0").unwrap()
+        );
+        assert_eq!(
+            y.saturating_sub(&x),
+            UnsignedInteger::from_str("485").unwrap()
+        );
+    }
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_sub_underflow_panics() {
+        let x = UnsignedInteger::from_str("1").unwrap();
+        let y = UnsignedInteger::from_str("2").unwrap();
+        let _ = x - y;
+    }
+
+    #[test]
+    fn test_mul_single_digits() {
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"]
+            .iter()
+            .map(|x| {
+                (
+                    UnsignedInteger::from_str(x).unwrap(),
+                    u32::from_str(x).unwrap(),
+                )
+            });
+        use itertools::Itertools;
+        digits
+            .clone()
+            .cartesian_product(digits)
+            .map(|((big_x, x), (big_y, y))| (big_x * big_y, x * y))
+            .map(|(big, little)| (format!("{}", big), format!("{}", little)))
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_mul() {
+        let cases = [
+            "0", "6", "12", "15", "50", "75", "128", "613", "1024", "4221", "7555",
+        ]
+        .iter()
+        .map(|x| {
+            (
+                UnsignedInteger::from_str(x).unwrap(),
+                u32::from_str(x).unwrap(),
+            )
+        });
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .map(|((big_x, x), (big_y, y))| (big_x * big_y, x * y))
+            .map(|(big, little)| (format!("{}", big), format!("{}", little)))
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_mul_karatsuba_matches_schoolbook() {
+        let cases = [
+            "0",
+            "7",
+            "613",
+            "4221",
+            "99999999",
+            "123456789012",
+            "7555000000000000000001",
+        ]
+        .iter()
+        .map(|x| UnsignedInteger::from_str(x).unwrap());
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .map(|(x, y)| {
+                (
+                    x.mul_with_threshold(&y, 1),
+                    x.mul_with_threshold(&y, usize::MAX),
+                )
+            })
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_mul_large() {
+        let x = UnsignedInteger::from_str("6106370327537124369917353116076419185226836738092151283090256592406883297278429111051221535933437500").unwrap();
+        let y = UnsignedInteger::from_str("1551324303876771241884343113259599609378653143111280111834187291909203932606583041853156452338126705").unwrap();
+        let expected = "9472960697580301065266779543956131386386300843812718936577184384925014515231516178182814004244216010447859924426837896764082192677360140852398005119325085680143136453710151092228169833962321198437500".to_string();
+        assert_eq!(format!("{}", x.clone() * y.clone()), expected);
+        assert_eq!(format!("{}", x.mul_with_threshold(&y, 4)), expected);
+    }
+
+    //digits from a linear congruential generator, so timings do not depend on the value
+    fn pseudo_random(digits: usize, seed: u64) -> UnsignedInteger {
+        let s = (0..digits)
+            .scan(seed, |state, _| {
+                *state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                Some(char::from(b'1' + (*state >> 33) as u8 % 9))
+            })
+            .collect::<String>();
+        UnsignedInteger::from_str(&s).unwrap()
+    }
+
+    fn time<T, F: FnMut() -> T>(mut f: F) -> (T, std::time::Duration) {
+        let start = std::time::Instant::now();
+        let result = f();
+        (result, start.elapsed())
+    }
+
+    //the one digit per symbol addition the limbs replaced, adding the units and the carries
+    //in rounds until there are no carries left
+    fn symbol_add(a: &[Symbol], b: &[Symbol]) -> Vec<Symbol> {
+        let (mut units, mut tens) = (a.to_vec(), b.to_vec());
+        loop {
+            let n = std::cmp::max(units.len(), tens.len());
+            units.resize(n, Symbol::Zero);
+            tens.resize(n, Symbol::Zero);
+            let frame = units
+                .iter()
+                .zip(tens.iter())
+                .map(|(a, b)| a.value() + b.value())
+                .collect::<Vec<_>>();
+            units = frame
+                .iter()
+                .map(|x| Symbol::from_value(x % 10).unwrap())
+                .collect();
+            if frame.iter().all(|&x| x < 10) {
+                return units;
+            }
+            tens = std::iter::once(Symbol::Zero)
+                .chain(frame.iter().map(|x| Symbol::from_value(x / 10).unwrap()))
+                .collect();
+        }
+    }
+
+    fn symbols(x: &UnsignedInteger) -> Vec<Symbol> {
+        x.to_string()
+            .chars()
+            .rev()
+            .map(|c| Symbol::from_char(&c).unwrap())
+            .collect()
+    }
+
+    //timings, so ignored by default, run with
+    //cargo test --release -- --ignored bench_
+    //limbs took 2.5µs against 64µs at 10k digits, 25µs against 0.73ms at 100k, 0.31ms against 11ms at 1M
+    #[test]
+    #[ignore]
+    fn bench_add_limbs_against_symbols() {
+        [10_000, 100_000, 1_000_000].iter().for_each(|&digits| {
+            let (x, y) = (pseudo_random(digits, 1), pseudo_random(digits, 2));
+            let (a, b) = (symbols(&x), symbols(&y));
+            let (limb_sum, limb_time) = time(|| &x + &y);
+            let (symbol_sum, symbol_time) = time(|| symbol_add(&a, &b));
+            assert_eq!(limb_sum, UnsignedInteger::raw(&symbol_sum));
+            assert!(limb_time < symbol_time, "{} digits", digits);
+        });
+    }
+    #[test]
+    #[ignore]
+    fn bench_mul_schoolbook_against_karatsuba() {
+        [10_000, 100_000, 1_000_000].iter().for_each(|&digits| {
+            let (x, y) = (pseudo_random(digits, 1), pseudo_random(digits, 2));
+            let (karatsuba, karatsuba_time) = time(|| &x * &y);
+            let (schoolbook, schoolbook_time) = time(|| x.mul_with_threshold(&y, usize::MAX));
+            assert_eq!(karatsuba, schoolbook);
+            assert!(karatsuba_time < schoolbook_time, "{} digits", digits);
+        });
+    }
+    #[test]
+    #[ignore]
+    fn bench_mul_threshold() {
+        let (x, y) = (pseudo_random(100_000, 1), pseudo_random(100_000, 2));
+        let expected = &x * &y;
+        let best = |threshold| {
+            (0..5)
+                .map(|_| time(|| x.mul_with_threshold(&y, threshold)))
+                .inspect(|(product, _)| assert_eq!(product, &expected))
+                .map(|(_, elapsed)| elapsed)
+                .min()
+                .unwrap()
+        };
+        //the chosen threshold is within a tenth of the fastest of the alternatives
+        let fastest = [9, 36, 90, 180, 720, 1440, 2880]
+            .iter()
+            .map(|&threshold| best(threshold))
+            .min()
+            .unwrap();
+        assert!(best(KARATSUBA_THRESHOLD) <= fastest + fastest / 10);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let cases = [
+            "0", "6", "12", "15", "50", "75", "128", "613", "1024", "4221", "7555",
+        ]
+        .iter()
+        .map(|x| {
+            (
+                UnsignedInteger::from_str(x).unwrap(),
+                u32::from_str(x).unwrap(),
+            )
+        });
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .filter(|(_, (_, y))| *y != 0)
+            .flat_map(|((big_x, x), (big_y, y))| {
+                vec![
+                    (big_x.clone() / big_y.clone(), x / y),
+                    (big_x % big_y, x % y),
+                ]
+            })
+            .map(|(big, little)| (format!("{}", big), format!("{}", little)))
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_div_rem_large() {
+        let x = UnsignedInteger::from_str("6106370327537124369917353116076419185226836738092151283090256592406883297278429111051221535933437500").unwrap();
+        let y = UnsignedInteger::from_str("1551324303876771241884343113259599609378653143111280111834187291909203932606583041853156452338126705").unwrap();
+        let z = UnsignedInteger::from_str("98765432109876543210").unwrap();
+        let (q, r) = (x.clone() * y.clone() + z.clone()).div_rem(&x);
+        assert_eq!(q, y);
+        assert_eq!(r, z);
+    }
+    #[test]
+    fn test_checked_div_by_zero() {
+        let x = UnsignedInteger::from_str("128").unwrap();
+        let zero = UnsignedInteger::from_str("0").unwrap();
+        assert_eq!(x.checked_div(&zero), Err(DivideByZeroError));
+        assert_eq!(x.checked_rem(&zero), Err(DivideByZeroError));
+        assert_eq!(x.checked_div_rem(&zero), Err(DivideByZeroError));
+    }
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_by_zero_panics() {
+        let x = UnsignedInteger::from_str("128").unwrap();
+        let zero = UnsignedInteger::from_str("0").unwrap();
+        let _ = x / zero;
+    }
+
+    #[test]
+    fn test_eq_ignores_leading_zeros() {
+        let x = UnsignedInteger::from_str("613").unwrap();
+        let padded = UnsignedInteger {
+            limbs: vec![613, 0, 0],
+        };
+        assert_eq!(padded, x);
+        assert_eq!(
+            UnsignedInteger::raw(&[Symbol::Zero, Symbol::Zero]),
+            UnsignedInteger::from_str("0").unwrap()
+        );
+    }
+    #[test]
+    fn test_ord() {
+        let cases = [
+            "0", "6", "12", "15", "50", "75", "128", "613", "1024", "4221", "7555",
+        ]
+        .iter()
+        .map(|x| {
+            (
+                UnsignedInteger::from_str(x).unwrap(),
+                u32::from_str(x).unwrap(),
+            )
+        });
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .map(|((big_x, x), (big_y, y))| (big_x.cmp(&big_y), x.cmp(&y)))
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_ord_padded() {
+        let x = UnsignedInteger {
+            limbs: vec![99, 0, 0],
+        };
+        let y = UnsignedInteger::from_str("100").unwrap();
+        assert!(x < y);
+        assert_eq!(
+            x.cmp(&UnsignedInteger::from_str("99").unwrap()),
+            Ordering::Equal
+        );
+    }
+    #[test]
+    fn test_sort_and_dedup() {
+        let mut values = ["75", "0", "1024", "075", "6", "00", "1024"]
+            .iter()
+            .map(|x| UnsignedInteger::from_str(x).unwrap())
+            .collect::<Vec<_>>();
+        values.push(UnsignedInteger { limbs: vec![6, 0] });
+        values.sort();
+        values.dedup();
+        assert_eq!(
+            values.iter().map(|x| format!("{}", x)).collect::<Vec<_>>(),
+            vec!["0", "6", "75", "1024"]
+        );
+    }
+    #[test]
+    fn test_hash_map_keys() {
+        use std::collections::{BTreeMap, HashMap};
+        let x = UnsignedInteger::from_str("613").unwrap();
+        let padded = UnsignedInteger {
+            limbs: vec![613, 0, 0, 0],
+        };
+        let mut hashed = HashMap::new();
+        hashed.insert(x.clone(), "x");
+        hashed.insert(padded.clone(), "padded");
+        assert_eq!(hashed.len(), 1);
+        assert_eq!(hashed.get(&x), Some(&"padded"));
+        let mut ordered = BTreeMap::new();
+        ordered.insert(x.clone(), "x");
+        ordered.insert(padded, "padded");
+        assert_eq!(ordered.len(), 1);
+    }
+
+    #[test]
+    fn test_add_long_carry_chain() {
+        let nines = "9".repeat(100_000);
+        let x = UnsignedInteger::from_str(&nines).unwrap();
+        let y = UnsignedInteger::from_str("1").unwrap();
+        let expected = format!("1{}", "0".repeat(100_000));
+        assert_eq!(format!("{}", &x + &y), expected);
+        assert_eq!(format!("{}", x + y), expected);
+    }
+    #[test]
+    fn test_add_by_reference() {
+        let x = UnsignedInteger::from_str("4221").unwrap();
+        let y = UnsignedInteger::from_str("7555").unwrap();
+        assert_eq!(&x + &y, UnsignedInteger::from_str("11776").unwrap());
+        //both operands are still usable
+        assert_eq!(x + y, UnsignedInteger::from_str("11776").unwrap());
+    }
+    #[test]
+    fn test_add_assign() {
+        let step = UnsignedInteger::from_str("999999999").unwrap();
+        let mut total = UnsignedInteger::from_str("0").unwrap();
+        (0..1000).for_each(|_| total += &step);
+        total += UnsignedInteger::from_str("1000").unwrap();
+        assert_eq!(total, UnsignedInteger::from_str("1000000000000").unwrap());
+    }
+
+    #[test]
+    fn test_reference_operators() {
+        let cases = ["1", "6", "12", "75", "613", "4221", "7555"]
+            .iter()
+            .map(|x| {
+                (
+                    UnsignedInteger::from_str(x).unwrap(),
+                    u32::from_str(x).unwrap(),
+                )
+            });
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .flat_map(|((big_x, x), (big_y, y))| {
+                let mut results = vec![
+                    (&big_x + &big_y, x + y),
+                    (&big_x * &big_y, x * y),
+                    (&big_x / &big_y, x / y),
+                    (&big_x % &big_y, x % y),
+                ];
+                if x >= y {
+                    results.push((&big_x - &big_y, x - y));
+                }
+                results
+            })
+            .map(|(big, little)| (format!("{}", big), format!("{}", little)))
+            .for_each(|(result, expected)| assert_eq!(result, expected));
+    }
+    #[test]
+    fn test_assign_operators() {
+        let cases = ["1", "6", "12", "75", "613", "4221", "7555"]
+            .iter()
+            .map(|x| {
+                (
+                    UnsignedInteger::from_str(x).unwrap(),
+                    u32::from_str(x).unwrap(),
+                )
+            });
+        use itertools::Itertools;
+        cases
+            .clone()
+            .cartesian_product(cases)
+            .for_each(|((big_x, x), (big_y, y))| {
+                let mut product = big_x.clone();
+                product *= &big_y;
+                assert_eq!(format!("{}", product), format!("{}", x * y));
+                let mut quotient = big_x.clone();
+                quotient /= &big_y;
+                assert_eq!(format!("{}", quotient), format!("{}", x / y));
+                let mut remainder = big_x.clone();
+                remainder %= big_y.clone();
+                assert_eq!(format!("{}", remainder), format!("{}", x % y));
+                if x >= y {
+                    let mut difference = big_x.clone();
+                    difference -= big_y;
+                    assert_eq!(format!("{}", difference), format!("{}", x - y));
+                }
+            });
+    }
+    #[test]
+    fn test_accumulate_by_reference() {
+        //1 + 2 + ... + 1000, then 20!
+        let values = (1..=1000)
+            .map(|x| UnsignedInteger::from_str(&x.to_string()).unwrap())
+            .collect::<Vec<_>>();
+        let mut total = UnsignedInteger::from_str("0").unwrap();
+        values.iter().for_each(|x| total += x);
+        assert_eq!(total, UnsignedInteger::from_str("500500").unwrap());
+        let mut factorial = UnsignedInteger::from_str("1").unwrap();
+        values.iter().take(20).for_each(|x| factorial *= x);
+        assert_eq!(
+            factorial,
+            UnsignedInteger::from_str("2432902008176640000").unwrap()
+        );
+        values.iter().take(20).for_each(|x| factorial /= x);
+        assert_eq!(factorial, UnsignedInteger::from_str("1").unwrap());
+    }
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_sub_assign_underflow_panics() {
+        let mut x = UnsignedInteger::from_str("1").unwrap();
+        x -= UnsignedInteger::from_str("2").unwrap();
+    }
+
+    #[test]
+    fn test_from_unsigned_primitives() {
+        assert_eq!(format!("{}", UnsignedInteger::from(0u8)), "0".to_string());
+        assert_eq!(
+            format!("{}", UnsignedInteger::from(u8::MAX)),
+            u8::MAX.to_string()
+        );
+        assert_eq!(
+            format!("{}", UnsignedInteger::from(u16::MAX)),
+            u16::MAX.to_string()
+        );
+        assert_eq!(
+            format!("{}", UnsignedInteger::from(u32::MAX)),
+            u32::MAX.to_string()
+        );
+        assert_eq!(
+            format!("{}", UnsignedInteger::from(u64::MAX)),
+            u64::MAX.to_string()
+        );
+        assert_eq!(
+            format!("{}", UnsignedInteger::from(u128::MAX)),
+            u128::MAX.to_string()
+        );
+        assert_eq!(
+            format!("{}", UnsignedInteger::from(usize::MAX)),
+            usize::MAX.to_string()
+        );
+        assert_eq!(
+            UnsignedInteger::from(1_000_000_000u64),
+            UnsignedInteger::from_str("1000000000").unwrap()
+        );
+    }
+    #[test]
+    fn test_try_into_primitives() {
+        let cases = [
+            "0",
+            "6",
+            "255",
+            "256",
+            "65535",
+            "65536",
+            "4294967295",
+            "4294967296",
+            "18446744073709551615",
+            "18446744073709551616",
+        ];
+        cases.iter().for_each(|x| {
+            let big = UnsignedInteger::from_str(x).unwrap();
+            assert_eq!(u8::try_from(&big).ok(), u8::from_str(x).ok());
+            assert_eq!(u16::try_from(&big).ok(), u16::from_str(x).ok());
+            assert_eq!(u32::try_from(&big).ok(), u32::from_str(x).ok());
+            assert_eq!(u64::try_from(&big).ok(), u64::from_str(x).ok());
+            assert_eq!(i8::try_from(&big).ok(), i8::from_str(x).ok());
+            assert_eq!(i32::try_from(&big).ok(), i32::from_str(x).ok());
+            assert_eq!(i64::try_from(&big).ok(), i64::from_str(x).ok());
+            assert_eq!(u128::try_from(big).ok(), u128::from_str(x).ok());
+        });
+    }
+    #[test]
+    fn test_try_into_u128_overflow() {
+        let max = UnsignedInteger::from(u128::MAX);
+        assert_eq!(u128::try_from(&max), Ok(u128::MAX));
+        let one = UnsignedInteger::from(1u8);
+        assert_eq!(u128::try_from(max + one), Err(OverflowError));
+        assert_eq!(
+            i128::try_from(UnsignedInteger::from(i128::MAX as u128 + 1)),
+            Err(OverflowError)
+        );
+    }
+    #[test]
+    fn test_try_from_signed_primitives() {
+        assert_eq!(
+            UnsignedInteger::try_from(42i64),
+            Ok(UnsignedInteger::from(42u8))
+        );
+        assert_eq!(
+            UnsignedInteger::try_from(i128::MAX),
+            Ok(UnsignedInteger::from(i128::MAX as u128))
+        );
+        assert_eq!(UnsignedInteger::try_from(-1i64), Err(NegativeValueError));
+        assert_eq!(
+            UnsignedInteger::try_from(i128::MIN),
+            Err(NegativeValueError)
+        );
+        assert_eq!(UnsignedInteger::try_from(-7i8), Err(NegativeValueError));
+    }
+
+    #[test]
+    fn test_interger_from_str_error_position() {
+        assert_eq!(
+            UnsignedInteger::from_str("1234567x90"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 7,
+                character: 'x'
+            })
+        );
+        //positions are byte offsets, and the first bad character is reported
+        assert_eq!(
+            UnsignedInteger::from_str("12é4-"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 2,
+                character: 'é'
+            })
+        );
+        assert_eq!(
+            UnsignedInteger::from_str("1-é"),
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 1,
+                character: '-'
+            })
+        );
+        assert_eq!(
+            UnsignedInteger::from_str(" 12"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 0,
+                character: ' '
+            })
+        );
+    }
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(
+            format!("{}", ParseUnsignedIntegerError::EmptyString),
+            "cannot parse integer from empty string".to_string()
+        );
+        assert_eq!(
+            format!("{}", UnsignedInteger::from_str("125-12").unwrap_err()),
+            "unexpected sign '-' at byte 3, an unsigned integer has no sign".to_string()
+        );
+        assert_eq!(
+            format!("{}", UnsignedInteger::from_str("12a").unwrap_err()),
+            "invalid digit 'a' at byte 2".to_string()
+        );
+    }
+    #[test]
+    fn test_parse_error_with_question_mark() {
+        fn sum(a: &str, b: &str) -> Result<UnsignedInteger, Box<dyn std::error::Error>> {
+            Ok(UnsignedInteger::from_str(a)? + UnsignedInteger::from_str(b)?)
+        }
+        assert_eq!(
+            sum("12", "30").unwrap(),
+            UnsignedInteger::from_str("42").unwrap()
+        );
+        assert_eq!(
+            sum("12", "3o").unwrap_err().to_string(),
+            "invalid digit 'o' at byte 1".to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_formatter_flags() {
+        ["0", "7", "4221", "18446744073709551615"]
+            .iter()
+            .for_each(|x| {
+                let big = UnsignedInteger::from_str(x).unwrap();
+                let little = u64::from_str(x).unwrap();
+                assert_eq!(format!("{:>30}", big), format!("{:>30}", little));
+                assert_eq!(format!("{:<30}|", big), format!("{:<30}|", little));
+                assert_eq!(format!("{:^30}", big), format!("{:^30}", little));
+                assert_eq!(format!("{:*^30}", big), format!("{:*^30}", little));
+                assert_eq!(format!("{:0>40}", big), format!("{:0>40}", little));
+                assert_eq!(format!("{:040}", big), format!("{:040}", little));
+                assert_eq!(format!("{:+}", big), format!("{:+}", little));
+                assert_eq!(format!("{:+08}", big), format!("{:+08}", little));
+                assert_eq!(format!("{:2}", big), format!("{:2}", little));
+            });
+    }
+    #[test]
+    fn test_display_width_large() {
+        let x = UnsignedInteger::from_str("6").unwrap();
+        let y = UnsignedInteger::from_str("1551324303876771241884343113259599609378653143111280111834187291909203932606583041853156452338126705").unwrap();
+        assert_eq!(format!("{:>100}", x), format!("{}6", " ".repeat(99)));
+        assert_eq!(format!("{:>100}", y), format!("{}", y));
+        assert_eq!(format!("{:>120}", y).len(), 120);
+    }
 }
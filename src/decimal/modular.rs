@@ -0,0 +1,285 @@
+use super::{limbs, UnsignedInteger};
+use std::convert::TryFrom;
+
+#[derive(Debug)]
+enum Reduction {
+    //for moduli sharing no factor with the limb base, values are kept times BASE^n mod m
+    Montgomery {
+        m_prime: u32,               //-m^-1 mod BASE
+        r_squared: UnsignedInteger, //BASE^2n mod m, for moving values into Montgomery form
+    },
+    //for any other modulus, mu is BASE^2n / m
+    Barrett {
+        mu: UnsignedInteger,
+    },
+}
+
+impl Clone for Reduction {
+    fn clone(&self) -> Self {
+        match self {
+            Reduction::Montgomery { m_prime, r_squared } => Reduction::Montgomery {
+                m_prime: *m_prime,
+                r_squared: r_squared.clone(),
+            },
+            Reduction::Barrett { mu } => Reduction::Barrett { mu: mu.clone() },
+        }
+    }
+}
+
+//drops the n least significant limbs, dividing by BASE^n
+fn shift_limbs_down(x: &UnsignedInteger, n: usize) -> UnsignedInteger {
+    UnsignedInteger::from_limbs(x.limbs.get(n..).map_or(Vec::new(), |limbs| limbs.to_vec()))
+}
+
+//a modulus with its reduction parameters worked out once, so multiplying by it
+//avoids long division, operands below the modulus are fastest as others are reduced first
+#[derive(Debug)]
+pub struct Modulus {
+    modulus: UnsignedInteger,
+    reduction: Reduction,
+}
+
+impl Clone for Modulus {
+    fn clone(&self) -> Self {
+        Modulus {
+            modulus: self.modulus.clone(),
+            reduction: self.reduction.clone(),
+        }
+    }
+}
+
+impl Modulus {
+    //panics if modulus is zero, like the remainder operator
+    pub fn new(modulus: &UnsignedInteger) -> Modulus {
+        assert!(
+            !modulus.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        let n = modulus.limbs.len();
+        //BASE^k as limbs
+        let base_power = |k: usize| {
+            let mut limbs = vec![0; k];
+            limbs.push(1);
+            UnsignedInteger::from_limbs(limbs)
+        };
+        let base = UnsignedInteger::from(limbs::BASE);
+        let reduction = match UnsignedInteger::from(modulus.limbs[0]).mod_inverse(&base) {
+            Some(inverse) => Reduction::Montgomery {
+                m_prime: limbs::BASE - u32::try_from(&inverse).unwrap(),
+                r_squared: &base_power(2 * n) % modulus,
+            },
+            None => Reduction::Barrett {
+                mu: &base_power(2 * n) / modulus,
+            },
+        };
+        Modulus {
+            modulus: modulus.clone(),
+            reduction,
+        }
+    }
+
+    pub fn value(&self) -> &UnsignedInteger {
+        &self.modulus
+    }
+
+    pub fn reduce(&self, x: &UnsignedInteger) -> UnsignedInteger {
+        if x < &self.modulus {
+            x.clone()
+        } else {
+            x % &self.modulus
+        }
+    }
+
+    //x mod m for x below m^2, without a long division
+    fn reduce_product(&self, x: &UnsignedInteger) -> UnsignedInteger {
+        match &self.reduction {
+            Reduction::Montgomery { m_prime, .. } => UnsignedInteger::from_limbs(
+                limbs::montgomery_reduce(&x.limbs, &self.modulus.limbs, *m_prime),
+            ),
+            Reduction::Barrett { mu } => {
+                let n = self.modulus.limbs.len();
+                //an estimate of x / m that is at most two too small
+                let quotient = shift_limbs_down(&(&shift_limbs_down(x, n - 1) * mu), n + 1);
+                let mut remainder = x - &(&quotient * &self.modulus);
+                while remainder >= self.modulus {
+                    remainder = &remainder - &self.modulus;
+                }
+                remainder
+            }
+        }
+    }
+
+    //moves a reduced value into the form products are kept in, and back out
+    fn enter(&self, x: &UnsignedInteger) -> UnsignedInteger {
+        match &self.reduction {
+            Reduction::Montgomery { r_squared, .. } => self.reduce_product(&(x * r_squared)),
+            Reduction::Barrett { .. } => x.clone(),
+        }
+    }
+
+    fn leave(&self, x: &UnsignedInteger) -> UnsignedInteger {
+        match &self.reduction {
+            Reduction::Montgomery { .. } => self.reduce_product(x),
+            Reduction::Barrett { .. } => x.clone(),
+        }
+    }
+
+    pub fn mul(&self, a: &UnsignedInteger, b: &UnsignedInteger) -> UnsignedInteger {
+        let product = &self.reduce(a) * &self.reduce(b);
+        //in Montgomery form the product picks up a factor of BASE^-n, which entering takes out
+        self.enter(&self.reduce_product(&product))
+    }
+
+    //base^exponent mod m, square and multiply over four bits of the exponent at a time
+    pub fn pow(&self, base: &UnsignedInteger, exponent: &UnsignedInteger) -> UnsignedInteger {
+        let one = self.enter(&self.reduce(&UnsignedInteger::from(1u8)));
+        let base = self.enter(&self.reduce(base));
+        let mul = |a: &UnsignedInteger, b: &UnsignedInteger| self.reduce_product(&(a * b));
+        let mut powers = vec![one.clone()];
+        for i in 1..16 {
+            powers.push(mul(&powers[i - 1], &base));
+        }
+        let mut result = one;
+        for byte in exponent.to_bytes_be() {
+            for nibble in [byte >> 4, byte & 0xF].iter() {
+                for _ in 0..4 {
+                    result = mul(&result, &result);
+                }
+                if *nibble != 0 {
+                    result = mul(&result, &powers[*nibble as usize]);
+                }
+            }
+        }
+        self.leave(&result)
+    }
+}
+
+impl UnsignedInteger {
+    //panics if modulus is zero
+    pub fn mod_pow(&self, exponent: &Self, modulus: &Self) -> UnsignedInteger {
+        Modulus::new(modulus).pow(self, exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::big;
+    use itertools::Itertools;
+
+    fn u128_mod_pow(base: u128, mut exponent: u128, modulus: u128) -> u128 {
+        //moduli below 2^64 keep every product inside a u128
+        let mut result = 1 % modulus;
+        let mut base = base % modulus;
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = result * base % modulus;
+            }
+            base = base * base % modulus;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    //the same with long division at every step, for checking the large cases
+    fn plain_mod_pow(
+        base: &UnsignedInteger,
+        exponent: &UnsignedInteger,
+        modulus: &UnsignedInteger,
+    ) -> UnsignedInteger {
+        exponent
+            .to_str_radix(2)
+            .chars()
+            .fold(&UnsignedInteger::from(1u8) % modulus, |acc, bit| {
+                let squared = &(&acc * &acc) % modulus;
+                if bit == '1' {
+                    &(&squared * base) % modulus
+                } else {
+                    squared
+                }
+            })
+    }
+
+    #[test]
+    fn test_mod_pow_matches_primitive() {
+        //odd moduli not divisible by five use Montgomery reduction, the rest Barrett
+        let moduli = [
+            1u128,
+            2,
+            3,
+            10,
+            97,
+            1000,
+            999_999_999,
+            1_000_000_000,
+            1_000_000_007,
+            4_294_967_296,
+            123_456_789_012_345,
+            u64::MAX as u128 - 58, //the largest prime below 2^64
+            u64::MAX as u128,
+        ];
+        let values = [0u128, 1, 2, 7, 12345, 999_999_999_999, u64::MAX as u128];
+        moduli
+            .iter()
+            .cartesian_product(values.iter().cartesian_product(values.iter()))
+            .for_each(|(&m, (&b, &e))| {
+                assert_eq!(
+                    UnsignedInteger::from(b)
+                        .mod_pow(&UnsignedInteger::from(e), &UnsignedInteger::from(m)),
+                    UnsignedInteger::from(u128_mod_pow(b, e, m))
+                )
+            });
+    }
+    #[test]
+    fn test_modulus_mul() {
+        let moduli = [1u128, 6, 97, 1_000_000_000, 1_000_000_007, u64::MAX as u128];
+        let values = [0u128, 1, 5, 96, 999_999_999_999, u64::MAX as u128];
+        moduli.iter().for_each(|&m| {
+            let modulus = Modulus::new(&UnsignedInteger::from(m));
+            values
+                .iter()
+                .cartesian_product(values.iter())
+                .for_each(|(&a, &b)| {
+                    assert_eq!(
+                        modulus.mul(&UnsignedInteger::from(a), &UnsignedInteger::from(b)),
+                        UnsignedInteger::from(a % m * (b % m) % m)
+                    )
+                });
+        });
+    }
+    #[test]
+    fn test_mod_pow_large() {
+        //Fermat's little theorem for the Mersenne prime 2^521 - 1
+        let p = &UnsignedInteger::from(2u8).pow(521) - &UnsignedInteger::from(1u8);
+        let p_less_one = &p - &UnsignedInteger::from(1u8);
+        [2u32, 3, 1_000_000_000].iter().for_each(|&a| {
+            assert_eq!(
+                UnsignedInteger::from(a).mod_pow(&p_less_one, &p),
+                UnsignedInteger::from(1u8)
+            )
+        });
+        //2048 bit moduli, one for each kind of reduction
+        let odd = &UnsignedInteger::from(3u8).pow(1292) + &UnsignedInteger::from(2u8);
+        let even = &odd * &UnsignedInteger::from(10u8);
+        let base = big(&"31415926535897932384626433832795028841971".repeat(3));
+        let exponent = &UnsignedInteger::from(7u8).pow(200) + &UnsignedInteger::from(1u8);
+        [odd, even].iter().for_each(|m| {
+            assert_eq!(
+                base.mod_pow(&exponent, m),
+                plain_mod_pow(&base, &exponent, m)
+            );
+            let modulus = Modulus::new(m);
+            let a = &base.pow(20) % m;
+            assert_eq!(modulus.mul(&a, &a), &(&a * &a) % m);
+            assert_eq!(
+                modulus.pow(&a, &UnsignedInteger::from(0u8)),
+                UnsignedInteger::from(1u8)
+            );
+        });
+    }
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn test_modulus_zero() {
+        let _ = Modulus::new(&UnsignedInteger::from(0u8));
+    }
+}
@@ -0,0 +1,298 @@
+use super::{limbs, Integer, UnsignedInteger};
+use std::convert::TryFrom;
+
+//leading digits Lehmer's method works on, small enough for the cofactors to fit in an i64
+const LEHMER_DIGITS: usize = 18;
+
+//s * a + t * b for cofactors of opposite signs, where the caller knows the result is not negative
+fn combine(a: &UnsignedInteger, s: i64, b: &UnsignedInteger, t: i64) -> UnsignedInteger {
+    let scaled = |x: &UnsignedInteger, m: i64| x * &UnsignedInteger::from(m.unsigned_abs());
+    if t <= 0 {
+        &scaled(a, s) - &scaled(b, t)
+    } else {
+        &scaled(b, t) - &scaled(a, s)
+    }
+}
+
+impl UnsignedInteger {
+    pub(super) fn is_even(&self) -> bool {
+        self.limbs.first().map_or(true, |x| x % 2 == 0)
+    }
+
+    pub(super) fn halve(&self) -> UnsignedInteger {
+        let (half, _) = limbs::div_rem_small(&self.limbs, 2);
+        UnsignedInteger::from_limbs(half)
+    }
+
+    //Lehmer's method, which runs Euclid's algorithm on the leading digits for as long as
+    //they decide the quotients, then applies the steps to the whole numbers at once
+    pub fn gcd(&self, other: &Self) -> UnsignedInteger {
+        let (mut a, mut b) = if self >= other {
+            (self.clone(), other.clone())
+        } else {
+            (other.clone(), self.clone())
+        };
+        while !b.is_zero() {
+            if let Ok(small) = u128::try_from(&a) {
+                //both fit in a machine word, so finish there
+                let mut x = small;
+                let mut y = u128::try_from(&b).unwrap();
+                while y != 0 {
+                    (x, y) = (y, x % y);
+                }
+                return UnsignedInteger::from(x);
+            }
+            let shift = a.digit_count() - LEHMER_DIGITS;
+            let mut x = u64::try_from(&a.shr10(shift)).unwrap() as i64;
+            let mut y = u64::try_from(&b.shr10(shift)).unwrap() as i64;
+            let (mut s0, mut t0, mut s1, mut t1) = (1i64, 0i64, 0i64, 1i64);
+            //the quotient is only certain when both ends of its possible range agree
+            while y + s1 != 0 && y + t1 != 0 {
+                let q = (x + s0) / (y + s1);
+                if q != (x + t0) / (y + t1) {
+                    break;
+                }
+                (s0, s1) = (s1, s0 - q * s1);
+                (t0, t1) = (t1, t0 - q * t1);
+                (x, y) = (y, x - q * y);
+            }
+            if t0 == 0 {
+                //the leading digits settled nothing, so take a whole step
+                let r = &a % &b;
+                a = b;
+                b = r;
+            } else {
+                let next_a = combine(&a, s0, &b, t0);
+                let next_b = combine(&a, s1, &b, t1);
+                a = next_a;
+                b = next_b;
+            }
+        }
+        a
+    }
+
+    //Stein's method, which needs only halving and subtraction
+    pub fn binary_gcd(&self, other: &Self) -> UnsignedInteger {
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let mut twos = 0;
+        while a.is_even() && b.is_even() {
+            a = a.halve();
+            b = b.halve();
+            twos += 1;
+        }
+        while a.is_even() {
+            a = a.halve();
+        }
+        //a stays odd from here on
+        while !b.is_zero() {
+            while b.is_even() {
+                b = b.halve();
+            }
+            if a > b {
+                std::mem::swap(&mut a, &mut b);
+            }
+            b = &b - &a;
+        }
+        (0..twos).fold(a, |x, _| &x + &x)
+    }
+
+    //zero if either is zero
+    pub fn lcm(&self, other: &Self) -> UnsignedInteger {
+        if self.is_zero() || other.is_zero() {
+            UnsignedInteger::zero()
+        } else {
+            &(self / &self.gcd(other)) * other
+        }
+    }
+
+    //the gcd g with x and y such that self * x + other * y = g
+    pub fn extended_gcd(&self, other: &Self) -> (UnsignedInteger, Integer, Integer) {
+        let one = || Integer::from(UnsignedInteger::from(1u8));
+        let zero = || Integer::from(UnsignedInteger::zero());
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (one(), zero());
+        let (mut t0, mut t1) = (zero(), one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            let q = Integer::from(q);
+            let s = s0 - q.clone() * s1.clone();
+            let t = t0 - q * t1.clone();
+            s0 = s1;
+            s1 = s;
+            t0 = t1;
+            t1 = t;
+            r0 = r1;
+            r1 = r;
+        }
+        (r0, s0, t0)
+    }
+
+    //the x below modulus with self * x = 1 mod modulus, if self and modulus are coprime
+    pub fn mod_inverse(&self, modulus: &Self) -> Option<UnsignedInteger> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let (g, x, _) = (self % modulus).extended_gcd(modulus);
+        if g != UnsignedInteger::from(1u8) {
+            None
+        } else if x.is_negative() {
+            Some(modulus - &(x.magnitude() % modulus))
+        } else {
+            Some(x.magnitude() % modulus)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::big;
+    use itertools::Itertools;
+    use std::str::FromStr;
+
+    const CASES: [u128; 16] = [
+        0,
+        1,
+        2,
+        6,
+        12,
+        35,
+        97,
+        1024,
+        4221,
+        7555,
+        999_999_999,
+        1_000_000_000,
+        123_456_789_012_345_678,
+        u64::MAX as u128,
+        340_282_366_920_938_463_463_374_607_431_768_211_297, //the largest prime below 2^128
+        u128::MAX,
+    ];
+
+    fn u128_gcd(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    #[test]
+    fn test_gcd_matches_primitive() {
+        CASES
+            .iter()
+            .cartesian_product(CASES.iter())
+            .for_each(|(&a, &b)| {
+                let (x, y) = (UnsignedInteger::from(a), UnsignedInteger::from(b));
+                let expected = UnsignedInteger::from(u128_gcd(a, b));
+                assert_eq!(x.gcd(&y), expected);
+                assert_eq!(x.binary_gcd(&y), expected);
+            });
+    }
+    #[test]
+    fn test_gcd_large() {
+        let a = big(&"1234567890".repeat(30));
+        let b = big(&"9876543210".repeat(25));
+        let common = UnsignedInteger::from(7u8).pow(90);
+        let (x, y) = (&a * &common, &b * &common);
+        let expected = &a.binary_gcd(&b) * &common;
+        assert_eq!(x.gcd(&y), expected);
+        assert_eq!(x.binary_gcd(&y), expected);
+        assert_eq!(y.gcd(&x), expected);
+        //consecutive Fibonacci numbers are coprime and the slowest case for Euclid
+        let (mut f0, mut f1) = (UnsignedInteger::from(0u8), UnsignedInteger::from(1u8));
+        (0..2000).for_each(|_| {
+            let f2 = &f0 + &f1;
+            f0 = f1.clone();
+            f1 = f2;
+        });
+        assert_eq!(f0.gcd(&f1), UnsignedInteger::from(1u8));
+        assert_eq!(f1.binary_gcd(&f0), UnsignedInteger::from(1u8));
+    }
+    #[test]
+    fn test_lcm() {
+        CASES
+            .iter()
+            .cartesian_product(CASES.iter())
+            .for_each(|(&a, &b)| {
+                let expected = if a == 0 || b == 0 {
+                    Some(0)
+                } else {
+                    (a / u128_gcd(a, b)).checked_mul(b)
+                };
+                if let Some(expected) = expected {
+                    assert_eq!(
+                        UnsignedInteger::from(a).lcm(&UnsignedInteger::from(b)),
+                        UnsignedInteger::from(expected)
+                    );
+                }
+            });
+        assert_eq!(
+            UnsignedInteger::from(u128::MAX).lcm(&UnsignedInteger::from(2u8)),
+            &UnsignedInteger::from(u128::MAX) * &UnsignedInteger::from(2u8)
+        );
+    }
+    #[test]
+    fn test_extended_gcd() {
+        CASES
+            .iter()
+            .cartesian_product(CASES.iter())
+            .for_each(|(&a, &b)| {
+                let (x, y) = (UnsignedInteger::from(a), UnsignedInteger::from(b));
+                let (g, s, t) = x.extended_gcd(&y);
+                assert_eq!(g, UnsignedInteger::from(u128_gcd(a, b)));
+                assert_eq!(
+                    Integer::from(x) * s + Integer::from(y) * t,
+                    Integer::from(g)
+                );
+            });
+        //Bezout coefficients are signed, eg 240 * -9 + 46 * 47 = 2
+        let (g, s, t) = UnsignedInteger::from(240u8).extended_gcd(&UnsignedInteger::from(46u8));
+        assert_eq!(g, UnsignedInteger::from(2u8));
+        assert_eq!(s, Integer::from_str("-9").unwrap());
+        assert_eq!(t, Integer::from_str("47").unwrap());
+    }
+    #[test]
+    fn test_mod_inverse() {
+        let modulus = 1_000_000_007u128;
+        [
+            1u128,
+            2,
+            3,
+            12345,
+            999_999_999,
+            1_000_000_006,
+            1_000_000_008,
+        ]
+        .iter()
+        .for_each(|&a| {
+            let inverse = UnsignedInteger::from(a)
+                .mod_inverse(&UnsignedInteger::from(modulus))
+                .unwrap();
+            let inverse = u128::try_from(&inverse).unwrap();
+            assert!(inverse < modulus);
+            assert_eq!(a % modulus * inverse % modulus, 1);
+        });
+        [(6u128, 9u128), (0, 7), (10, 0), (4, 1024), (35, 7555)]
+            .iter()
+            .for_each(|&(a, m)| {
+                assert_eq!(
+                    UnsignedInteger::from(a).mod_inverse(&UnsignedInteger::from(m)),
+                    None
+                )
+            });
+        assert_eq!(
+            UnsignedInteger::from(5u8).mod_inverse(&UnsignedInteger::from(1u8)),
+            Some(UnsignedInteger::from(0u8))
+        );
+        let m = UnsignedInteger::from(2u8).pow(521) - UnsignedInteger::from(1u8);
+        let a = big(&"1234567890".repeat(10));
+        let inverse = a.mod_inverse(&m).unwrap();
+        assert_eq!(&(&a * &inverse) % &m, UnsignedInteger::from(1u8));
+    }
+}
@@ -0,0 +1,455 @@
+use super::{ParseUnsignedIntegerError, Symbol, UnsignedInteger};
+
+//the zero of every run of ten Unicode decimal digits, as of Unicode 16.0.0, generated from
+//UnicodeData.txt with awk -F';' '$3 == "Nd" && $7 == "0" { print $1 }', which lists the
+//General_Category=Nd code points whose decimal digit value is zero
+//each run holds zero to nine in order, so a digit's value is its offset from the zero
+const UNICODE_ZEROS: [char; 76] = [
+    '\u{0030}',
+    '\u{0660}',
+    '\u{06F0}',
+    '\u{07C0}',
+    '\u{0966}',
+    '\u{09E6}',
+    '\u{0A66}',
+    '\u{0AE6}',
+    '\u{0B66}',
+    '\u{0BE6}',
+    '\u{0C66}',
+    '\u{0CE6}',
+    '\u{0D66}',
+    '\u{0DE6}',
+    '\u{0E50}',
+    '\u{0ED0}',
+    '\u{0F20}',
+    '\u{1040}',
+    '\u{1090}',
+    '\u{17E0}',
+    '\u{1810}',
+    '\u{1946}',
+    '\u{19D0}',
+    '\u{1A80}',
+    '\u{1A90}',
+    '\u{1B50}',
+    '\u{1BB0}',
+    '\u{1C40}',
+    '\u{1C50}',
+    '\u{A620}',
+    '\u{A8D0}',
+    '\u{A900}',
+    '\u{A9D0}',
+    '\u{A9F0}',
+    '\u{AA50}',
+    '\u{ABF0}',
+    '\u{FF10}',
+    '\u{104A0}',
+    '\u{10D30}',
+    '\u{10D40}',
+    '\u{11066}',
+    '\u{110F0}',
+    '\u{11136}',
+    '\u{111D0}',
+    '\u{112F0}',
+    '\u{11450}',
+    '\u{114D0}',
+    '\u{11650}',
+    '\u{116C0}',
+    '\u{116D0}',
+    '\u{116DA}',
+    '\u{11730}',
+    '\u{118E0}',
+    '\u{11950}',
+    '\u{11BF0}',
+    '\u{11C50}',
+    '\u{11D50}',
+    '\u{11DA0}',
+    '\u{11F50}',
+    '\u{16130}',
+    '\u{16A60}',
+    '\u{16AC0}',
+    '\u{16B50}',
+    '\u{16D70}',
+    '\u{1CCF0}',
+    '\u{1D7CE}',
+    '\u{1D7D8}',
+    '\u{1D7E2}',
+    '\u{1D7EC}',
+    '\u{1D7F6}',
+    '\u{1E140}',
+    '\u{1E2F0}',
+    '\u{1E4F0}',
+    '\u{1E5F1}',
+    '\u{1E950}',
+    '\u{1FBF0}',
+];
+
+fn unicode_digit(c: char) -> Option<Symbol> {
+    //find the last zero at or below c, then check c is within nine of it
+    let index = UNICODE_ZEROS.partition_point(|&zero| zero <= c);
+    index
+        .checked_sub(1)
+        .map(|i| c as u32 - UNICODE_ZEROS[i] as u32)
+        .filter(|&offset| offset < 10)
+        .and_then(|offset| Symbol::from_value(offset as u8))
+}
+
+//a configurable parser, the defaults match the strict FromStr for UnsignedInteger
+#[derive(Debug)]
+pub struct ParseOptions {
+    separators: Vec<char>,
+    trim_whitespace: bool,
+    unicode_digits: bool,
+    radix: u32,
+    radix_prefix: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+impl Clone for ParseOptions {
+    fn clone(&self) -> Self {
+        ParseOptions {
+            separators: self.separators.clone(),
+            trim_whitespace: self.trim_whitespace,
+            unicode_digits: self.unicode_digits,
+            radix: self.radix,
+            radix_prefix: self.radix_prefix,
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn new() -> ParseOptions {
+        ParseOptions {
+            separators: Vec::new(),
+            trim_whitespace: false,
+            unicode_digits: false,
+            radix: 10,
+            radix_prefix: false,
+        }
+    }
+
+    //allows c between digits as a group separator, eg ',' in "1,234,567"
+    pub fn separator(mut self, c: char) -> ParseOptions {
+        self.separators.push(c);
+        self
+    }
+
+    pub fn separators(mut self, cs: &[char]) -> ParseOptions {
+        self.separators.extend_from_slice(cs);
+        self
+    }
+
+    //ignores leading and trailing whitespace
+    pub fn trim_whitespace(mut self, trim: bool) -> ParseOptions {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    //accepts decimal digits from any script, eg Arabic-Indic or Devanagari digits
+    pub fn unicode_digits(mut self, allow: bool) -> ParseOptions {
+        self.unicode_digits = allow;
+        self
+    }
+
+    //panics if radix is not in the range 2 to 36, like UnsignedInteger::from_str_radix
+    pub fn radix(mut self, radix: u32) -> ParseOptions {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2 to 36, got {}",
+            radix
+        );
+        self.radix = radix;
+        self
+    }
+
+    //accepts a leading 0x, 0o or 0b, which then overrides the radix, unless the letter is
+    //itself a digit in the radix, so "0b1" in radix 16 is 0xB1
+    pub fn radix_prefix(mut self, allow: bool) -> ParseOptions {
+        self.radix_prefix = allow;
+        self
+    }
+
+    fn digit(&self, c: char, radix: u32) -> Option<u8> {
+        let decimal = Symbol::from_char(&c).or_else(|| {
+            if self.unicode_digits {
+                unicode_digit(c)
+            } else {
+                None
+            }
+        });
+        decimal
+            .map(|symbol| symbol.value())
+            .or_else(|| c.to_digit(radix).map(|d| d as u8))
+            .filter(|&d| (d as u32) < radix)
+    }
+
+    //splits off a radix prefix if there is one and they are allowed
+    fn prefix<'a>(&self, s: &'a str) -> (u32, &'a str) {
+        let radix = match s.get(..2) {
+            Some("0x") | Some("0X") => Some(16),
+            Some("0o") | Some("0O") => Some(8),
+            Some("0b") | Some("0B") => Some(2),
+            _ => None,
+        };
+        //a letter that is a digit in the configured radix is read as one
+        let letter_is_digit = s
+            .chars()
+            .nth(1)
+            .map_or(false, |c| self.digit(c, self.radix).is_some());
+        match radix {
+            Some(radix) if self.radix_prefix && !letter_is_digit => (radix, &s[2..]),
+            _ => (self.radix, s),
+        }
+    }
+
+    //error positions are byte offsets into s, before any trimming
+    pub fn parse(&self, s: &str) -> Result<UnsignedInteger, ParseUnsignedIntegerError> {
+        let (offset, trimmed) = if self.trim_whitespace {
+            let start = s.trim_start();
+            (s.len() - start.len(), start.trim_end())
+        } else {
+            (0, s)
+        };
+        let (radix, unprefixed) = self.prefix(trimmed);
+        let offset = offset + trimmed.len() - unprefixed.len();
+        let trimmed = unprefixed;
+        if trimmed.is_empty() {
+            Err(ParseUnsignedIntegerError::EmptyString)
+        } else {
+            let chars = trimmed.char_indices().collect::<Vec<_>>();
+            let mut digits = Vec::with_capacity(chars.len());
+            for (i, &(position, c)) in chars.iter().enumerate() {
+                match self.digit(c, radix) {
+                    Some(digit) => digits.push(digit),
+                    None => {
+                        //a separator has to sit between two digits
+                        let between_digits = i > 0
+                            && self.digit(chars[i - 1].1, radix).is_some()
+                            && chars
+                                .get(i + 1)
+                                .map_or(false, |&(_, next)| self.digit(next, radix).is_some());
+                        if !(self.separators.contains(&c) && between_digits) {
+                            return Err(ParseUnsignedIntegerError::unexpected(
+                                offset + position,
+                                c,
+                            ));
+                        }
+                    }
+                }
+            }
+            Ok(UnsignedInteger::from_radix_digits(&digits, radix))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_default_options_are_strict() {
+        ["0", "12512", "007", "", "1,000", " 12", "-1", "+10", "١٢"]
+            .iter()
+            .for_each(|x| assert_eq!(ParseOptions::new().parse(x), UnsignedInteger::from_str(x)));
+    }
+    #[test]
+    fn test_separators() {
+        let expected = UnsignedInteger::from_str("1234567").unwrap();
+        let options = ParseOptions::new().separators(&[',', '_', ' ']);
+        [
+            "1,234,567",
+            "1_234_567",
+            "1 234 567",
+            "12,34,567",
+            "1234567",
+        ]
+        .iter()
+        .for_each(|x| assert_eq!(options.parse(x), Ok(expected.clone())));
+    }
+    #[test]
+    fn test_misplaced_separators() {
+        let options = ParseOptions::new().separator(',');
+        [(",1", 0), ("1,", 1), ("1,,2", 1), ("1,_2", 1)]
+            .iter()
+            .for_each(|(x, position)| {
+                let character = x[*position..].chars().next().unwrap();
+                assert_eq!(
+                    options.parse(x),
+                    Err(ParseUnsignedIntegerError::InvalidDigit {
+                        position: *position,
+                        character
+                    })
+                )
+            });
+        assert_eq!(
+            options.parse(","),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 0,
+                character: ','
+            })
+        );
+    }
+    #[test]
+    fn test_trim_whitespace() {
+        let options = ParseOptions::new().trim_whitespace(true);
+        assert_eq!(
+            options.parse("  42\n"),
+            Ok(UnsignedInteger::from_str("42").unwrap())
+        );
+        assert_eq!(
+            options.parse(" \t "),
+            Err(ParseUnsignedIntegerError::EmptyString)
+        );
+        //positions still refer to the untrimmed input
+        assert_eq!(
+            options.parse("  4-2 "),
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 3,
+                character: '-'
+            })
+        );
+        assert_eq!(
+            options.parse("4 2"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 1,
+                character: ' '
+            })
+        );
+    }
+    #[test]
+    fn test_spreadsheet_input() {
+        let options = ParseOptions::new()
+            .trim_whitespace(true)
+            .separators(&[',', ' ']);
+        assert_eq!(
+            options.parse(" 1 000 000 "),
+            Ok(UnsignedInteger::from_str("1000000").unwrap())
+        );
+        assert_eq!(
+            options.parse("\t12,512"),
+            Ok(UnsignedInteger::from_str("12512").unwrap())
+        );
+    }
+    #[test]
+    fn test_unicode_digits() {
+        let options = ParseOptions::new().unicode_digits(true);
+        [
+            ("١٢٣", "123"),          //Arabic-Indic
+            ("۴۵۶", "456"),          //Extended Arabic-Indic
+            ("४२", "42"),            //Devanagari
+            ("১০", "10"),            //Bengali
+            ("๙๙", "99"),            //Thai
+            ("１２５１２", "12512"), //Fullwidth
+            ("𝟗𝟎", "90"),            //Mathematical bold
+            ("1٢3", "123"),          //mixed with ASCII
+        ]
+        .iter()
+        .for_each(|(x, expected)| {
+            assert_eq!(
+                options.parse(x),
+                Ok(UnsignedInteger::from_str(expected).unwrap())
+            )
+        });
+        //other numeric characters are not decimal digits
+        assert_eq!(
+            options.parse("1½"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 1,
+                character: '½'
+            })
+        );
+        assert_eq!(
+            ParseOptions::new().parse("١٢٣"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 0,
+                character: '١'
+            })
+        );
+    }
+    #[test]
+    fn test_unicode_zero_table() {
+        UNICODE_ZEROS.iter().for_each(|&zero| {
+            (0..10).for_each(|i| {
+                let c = std::char::from_u32(zero as u32 + i).unwrap();
+                assert!(c.is_numeric());
+                assert_eq!(unicode_digit(c).map(|s| s.value() as u32), Some(i));
+            })
+        });
+        //sorted for the binary search, with no two runs overlapping
+        assert!(UNICODE_ZEROS
+            .windows(2)
+            .all(|w| w[1] as u32 >= w[0] as u32 + 10));
+    }
+    #[test]
+    fn test_radix() {
+        let options = ParseOptions::new().radix(16).separator('_');
+        assert_eq!(
+            options.parse("dead_BEEF"),
+            Ok(UnsignedInteger::from(0xdead_beefu32))
+        );
+        assert_eq!(
+            options.parse("0xff"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 1,
+                character: 'x'
+            })
+        );
+        assert_eq!(
+            ParseOptions::new().radix(2).parse("102"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 2,
+                character: '2'
+            })
+        );
+    }
+    #[test]
+    fn test_radix_prefix() {
+        let options = ParseOptions::new().radix_prefix(true).trim_whitespace(true);
+        [
+            ("0xff", 255u32),
+            ("0XFF", 255),
+            ("0o17", 15),
+            ("0b101", 5),
+            (" 0x10 ", 16),
+            ("42", 42),
+            ("0", 0),
+        ]
+        .iter()
+        .for_each(|(x, expected)| {
+            assert_eq!(options.parse(x), Ok(UnsignedInteger::from(*expected)))
+        });
+        assert_eq!(
+            options.parse("0x"),
+            Err(ParseUnsignedIntegerError::EmptyString)
+        );
+        //positions still count the prefix
+        assert_eq!(
+            options.parse("0b12"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 3,
+                character: '2'
+            })
+        );
+        //a prefix letter that is a digit in the radix is read as a digit
+        let hex = ParseOptions::new().radix(16).radix_prefix(true);
+        assert_eq!(hex.parse("0b1"), Ok(UnsignedInteger::from(0xB1u8)));
+        assert_eq!(hex.parse("0B1"), Ok(UnsignedInteger::from(0xB1u8)));
+        assert_eq!(hex.parse("0x1f"), Ok(UnsignedInteger::from(0x1Fu8)));
+        assert_eq!(hex.parse("0o17"), Ok(UnsignedInteger::from(15u8)));
+        let base36 = ParseOptions::new().radix(36).radix_prefix(true);
+        assert_eq!(base36.parse("0x"), Ok(UnsignedInteger::from(33u8)));
+        //without the option a prefix is just an invalid digit
+        assert_eq!(
+            ParseOptions::new().parse("0x10"),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 1,
+                character: 'x'
+            })
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use super::{limbs, UnsignedInteger};
+
+impl UnsignedInteger {
+    //number of decimal digits, where zero has one
+    pub fn digit_count(&self) -> usize {
+        match limbs::significant(&self.limbs).split_last() {
+            None => 1,
+            Some((top, rest)) => rest.len() * limbs::DIGITS + top.to_string().len(),
+        }
+    }
+
+    //0^0 is 1, like the primitive integer types
+    pub fn pow(&self, exponent: u32) -> UnsignedInteger {
+        self.pow_with_limit(exponent, usize::MAX).unwrap()
+    }
+
+    //None if the result would have more than max_digits digits, giving up before any
+    //partial result grows much past the limit
+    pub fn pow_with_limit(&self, exponent: u32, max_digits: usize) -> Option<UnsignedInteger> {
+        let too_long = |x: &UnsignedInteger| x.digit_count() > max_digits;
+        let mut result = UnsignedInteger::from(1u8);
+        //square and multiply from the top bit down, so every partial result is a smaller
+        //power of self and the first one over the limit means the answer is too
+        for bit in (0..32 - exponent.leading_zeros()).rev() {
+            //a square has at least twice the digits less one, so skip building it if that is too many
+            if (2 * result.digit_count()).saturating_sub(1) > max_digits {
+                return None;
+            }
+            result = &result * &result;
+            if exponent >> bit & 1 == 1 {
+                result = &result * self;
+            }
+            if too_long(&result) {
+                return None;
+            }
+        }
+        if too_long(&result) {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    pub fn pow10(n: usize) -> UnsignedInteger {
+        UnsignedInteger::from(1u8).shl10(n)
+    }
+
+    //multiplies by 10^n by moving whole digits
+    pub fn shl10(&self, n: usize) -> UnsignedInteger {
+        self.shift_digits_up(n)
+    }
+
+    //divides by 10^n by dropping whole digits, so the result is truncated
+    pub fn shr10(&self, n: usize) -> UnsignedInteger {
+        UnsignedInteger::from_limbs(limbs::shift_digits_down(&self.limbs, n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_pow_matches_primitive() {
+        let bases = [0u128, 1, 2, 3, 7, 10, 99, 1024, 4221];
+        let exponents = [0u32, 1, 2, 3, 5, 8, 10];
+        bases
+            .iter()
+            .cartesian_product(exponents.iter())
+            .filter_map(|(&x, &n)| x.checked_pow(n).map(|expected| (x, n, expected)))
+            .for_each(|(x, n, expected)| {
+                assert_eq!(
+                    UnsignedInteger::from(x).pow(n),
+                    UnsignedInteger::from(expected)
+                )
+            });
+    }
+    #[test]
+    fn test_pow_large() {
+        assert_eq!(
+            format!("{}", UnsignedInteger::from(10u8).pow(500)),
+            format!("1{}", "0".repeat(500))
+        );
+        let by_hand = (0..1000).fold(UnsignedInteger::from(1u8), |acc, _| {
+            acc * UnsignedInteger::from(7u8)
+        });
+        assert_eq!(UnsignedInteger::from(7u8).pow(1000), by_hand);
+        assert_eq!(by_hand.digit_count(), 846);
+    }
+    #[test]
+    fn test_pow_with_limit() {
+        let seven = UnsignedInteger::from(7u8);
+        assert_eq!(seven.pow_with_limit(1000, 846), Some(seven.pow(1000)));
+        assert_eq!(seven.pow_with_limit(1000, 845), None);
+        assert_eq!(seven.pow_with_limit(1_000_000_000, 1000), None);
+        assert_eq!(
+            UnsignedInteger::from(10u8).pow_with_limit(3, 4),
+            Some(UnsignedInteger::from(1000u16))
+        );
+        assert_eq!(UnsignedInteger::from(10u8).pow_with_limit(4, 4), None);
+        //values that do not grow stay within any limit
+        assert_eq!(
+            UnsignedInteger::from(1u8).pow_with_limit(u32::MAX, 1),
+            Some(UnsignedInteger::from(1u8))
+        );
+        assert_eq!(
+            UnsignedInteger::from(0u8).pow_with_limit(u32::MAX, 1),
+            Some(UnsignedInteger::from(0u8))
+        );
+        assert_eq!(UnsignedInteger::from(5u8).pow_with_limit(0, 0), None);
+    }
+    #[test]
+    fn test_digit_count() {
+        [
+            ("0", 1),
+            ("9", 1),
+            ("10", 2),
+            ("999999999", 9),
+            ("1000000000", 10),
+        ]
+        .iter()
+        .for_each(|(x, expected)| {
+            assert_eq!(
+                UnsignedInteger::from_str(x).unwrap().digit_count(),
+                *expected
+            )
+        });
+    }
+    #[test]
+    fn test_pow10() {
+        (0..40).for_each(|n| {
+            assert_eq!(
+                format!("{}", UnsignedInteger::pow10(n)),
+                format!("1{}", "0".repeat(n))
+            )
+        });
+    }
+    #[test]
+    fn test_shift() {
+        let cases = [
+            0u128,
+            1,
+            9,
+            10,
+            123_456_789,
+            1_000_000_000,
+            u64::MAX as u128,
+        ];
+        cases
+            .iter()
+            .cartesian_product(0..20u32)
+            .for_each(|(&x, n)| {
+                let big = UnsignedInteger::from(x);
+                assert_eq!(
+                    big.shr10(n as usize),
+                    UnsignedInteger::from(x / 10u128.pow(n))
+                );
+                assert_eq!(
+                    big.shl10(n as usize),
+                    UnsignedInteger::from(x * 10u128.pow(n))
+                );
+            });
+        let x = UnsignedInteger::from_str(&"1234567890".repeat(10)).unwrap();
+        assert_eq!(
+            format!("{}", x.shr10(35)),
+            "1234567890".repeat(10)[..65].to_string()
+        );
+        assert_eq!(x.shr10(100), UnsignedInteger::from(0u8));
+        assert_eq!(x.shr10(1000), UnsignedInteger::from(0u8));
+        assert_eq!(x.shl10(50).shr10(50), x);
+    }
+}
@@ -0,0 +1,402 @@
+use super::{limbs, Modulus, UnsignedInteger};
+use std::convert::TryFrom;
+
+//primes below this are tried as divisors before any of the probabilistic tests
+const TRIAL_BOUND: u32 = 1000;
+
+fn small_primes() -> impl Iterator<Item = u32> {
+    (2u32..).filter(|&p| (2..).take_while(|d| d * d <= p).all(|d| p % d != 0))
+}
+
+//the Jacobi symbol (a / n) for odd n
+fn jacobi(mut a: u64, mut n: u64) -> i32 {
+    let mut result = 1;
+    a %= n;
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        std::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+//x as d * 2^s with d odd, for x above zero
+fn split_twos(x: &UnsignedInteger) -> (UnsignedInteger, usize) {
+    let mut d = x.clone();
+    let mut s = 0;
+    while d.is_even() {
+        d = d.halve();
+        s += 1;
+    }
+    (d, s)
+}
+
+impl UnsignedInteger {
+    fn rem_small(&self, d: u32) -> u32 {
+        limbs::div_rem_small(&self.limbs, d).1
+    }
+
+    //Some(true) or Some(false) from the small primes alone, None if they cannot tell
+    fn small_prime_test(&self) -> Option<bool> {
+        if self < &UnsignedInteger::from(2u8) {
+            return Some(false);
+        }
+        for p in small_primes().take_while(|&p| p < TRIAL_BOUND) {
+            if self == &UnsignedInteger::from(p) {
+                return Some(true);
+            }
+            if self.rem_small(p) == 0 {
+                return Some(false);
+            }
+        }
+        //a composite has a factor no larger than its square root
+        if self < &UnsignedInteger::from(TRIAL_BOUND * TRIAL_BOUND) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    //whether self passes the strong probable prime test to the given base, for odd self above two
+    fn is_strong_probable_prime(&self, modulus: &Modulus, base: &UnsignedInteger) -> bool {
+        let one = UnsignedInteger::from(1u8);
+        let minus_one = self - &one;
+        let (d, s) = split_twos(&minus_one);
+        let mut x = modulus.pow(base, &d);
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = modulus.mul(&x, &x);
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    }
+
+    //strong Lucas probable prime test with Selfridge's parameters, for odd self that is not
+    //a perfect square and has no factor below TRIAL_BOUND
+    fn is_strong_lucas_probable_prime(&self, modulus: &Modulus) -> bool {
+        //the first D in 5, -7, 9, -11, ... with Jacobi symbol (D / self) of -1, which exists
+        //for any self that is not a square
+        let n4 = (self.limbs[0] % 4) as u64;
+        let d = (0..)
+            .map(|i| if i % 2 == 0 { 5 + 2 * i } else { -5 - 2 * i })
+            .find(|&d: &i64| {
+                let a = d.unsigned_abs();
+                let mut symbol = jacobi(self.rem_small(a as u32) as u64, a);
+                //quadratic reciprocity, then (-1 / self) for negative D
+                if a % 4 == 3 && n4 == 3 {
+                    symbol = -symbol;
+                }
+                if d < 0 && n4 == 3 {
+                    symbol = -symbol;
+                }
+                symbol == -1
+            })
+            .unwrap();
+        let residue = |x: i64| {
+            let r = modulus.reduce(&UnsignedInteger::from(x.unsigned_abs()));
+            if x < 0 && !r.is_zero() {
+                self - &r
+            } else {
+                r
+            }
+        };
+        let add = |a: &UnsignedInteger, b: &UnsignedInteger| {
+            let sum = a + b;
+            if &sum >= self {
+                &sum - self
+            } else {
+                sum
+            }
+        };
+        let sub = |a: &UnsignedInteger, b: &UnsignedInteger| {
+            if a >= b {
+                a - b
+            } else {
+                &(a + self) - b
+            }
+        };
+        let half = |a: UnsignedInteger| {
+            if a.is_even() {
+                a.halve()
+            } else {
+                (&a + self).halve()
+            }
+        };
+        let (big_d, q) = (residue(d), residue((1 - d) / 4));
+        let (k, s) = split_twos(&(self + &UnsignedInteger::from(1u8)));
+        //U_1 = 1 and V_1 = P = 1, doubling the index for each bit of k and adding one for
+        //each set bit, with qk tracking Q^index for the doubling formula of V
+        let (mut u, mut v, mut qk) = (
+            UnsignedInteger::from(1u8),
+            UnsignedInteger::from(1u8),
+            q.clone(),
+        );
+        for bit in k.to_str_radix(2).chars().skip(1) {
+            u = modulus.mul(&u, &v);
+            v = sub(&modulus.mul(&v, &v), &add(&qk, &qk));
+            qk = modulus.mul(&qk, &qk);
+            if bit == '1' {
+                let next_u = half(add(&u, &v));
+                v = half(add(&modulus.mul(&big_d, &u), &v));
+                u = next_u;
+                qk = modulus.mul(&qk, &q);
+            }
+        }
+        if u.is_zero() || v.is_zero() {
+            return true;
+        }
+        for _ in 1..s {
+            v = sub(&modulus.mul(&v, &v), &add(&qk, &qk));
+            if v.is_zero() {
+                return true;
+            }
+            qk = modulus.mul(&qk, &qk);
+        }
+        false
+    }
+
+    //certain, by dividing by every candidate up to the square root, so only practical for
+    //small values, None if self does not fit in a u64
+    pub fn is_prime_trial_division(&self) -> Option<bool> {
+        let n = u64::try_from(self).ok()?;
+        if n < 4 {
+            return Some(n >= 2);
+        }
+        if n % 2 == 0 || n % 3 == 0 {
+            return Some(false);
+        }
+        //every prime above three is one either side of a multiple of six
+        Some(
+            (1..)
+                .map(|i| 6 * i as u64)
+                .take_while(|&m| (m - 1).checked_mul(m - 1).map_or(false, |sq| sq <= n))
+                .all(|m| n % (m - 1) != 0 && n % (m + 1) != 0),
+        )
+    }
+
+    //Miller-Rabin with the first rounds primes as bases, false means self is certainly
+    //composite, true that it is prime or a strong pseudoprime to every base tried, with
+    //always at least one round, as none at all would prove nothing
+    pub fn miller_rabin(&self, rounds: usize) -> bool {
+        if self < &UnsignedInteger::from(2u8) {
+            return false;
+        }
+        if self.is_even() {
+            return self == &UnsignedInteger::from(2u8);
+        }
+        let modulus = Modulus::new(self);
+        small_primes()
+            .take(std::cmp::max(rounds, 1))
+            .map(|p| modulus.reduce(&UnsignedInteger::from(p)))
+            .filter(|base| !base.is_zero())
+            .all(|base| self.is_strong_probable_prime(&modulus, &base))
+    }
+
+    //Baillie-PSW, a strong probable prime test to base two and a strong Lucas test together,
+    //with no composite known to pass both and none below 2^64 that does
+    pub fn bpsw(&self) -> bool {
+        if let Some(prime) = self.small_prime_test() {
+            return prime;
+        }
+        let modulus = Modulus::new(self);
+        self.is_strong_probable_prime(&modulus, &UnsignedInteger::from(2u8))
+            && !self.is_perfect_square()
+            && self.is_strong_lucas_probable_prime(&modulus)
+    }
+
+    pub fn is_prime(&self) -> bool {
+        self.bpsw()
+    }
+
+    //the smallest prime above self
+    pub fn next_prime(&self) -> UnsignedInteger {
+        let two = UnsignedInteger::from(2u8);
+        if self < &two {
+            return two;
+        }
+        let mut candidate = self + &UnsignedInteger::from(1u8);
+        if candidate.is_even() {
+            candidate = &candidate + &UnsignedInteger::from(1u8);
+        }
+        while !candidate.is_prime() {
+            candidate = &candidate + &two;
+        }
+        candidate
+    }
+
+    //the largest prime below self, None if there is none
+    pub fn prev_prime(&self) -> Option<UnsignedInteger> {
+        let two = UnsignedInteger::from(2u8);
+        if self <= &two {
+            return None;
+        }
+        if self == &UnsignedInteger::from(3u8) {
+            return Some(two);
+        }
+        let mut candidate = self - &UnsignedInteger::from(1u8);
+        if candidate.is_even() {
+            candidate = &candidate - &UnsignedInteger::from(1u8);
+        }
+        //three is prime, so this stops before going below it
+        while !candidate.is_prime() {
+            candidate = &candidate - &two;
+        }
+        Some(candidate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sieve(limit: usize) -> Vec<bool> {
+        let mut prime = vec![true; limit];
+        prime[0] = false;
+        prime[1] = false;
+        (2..limit).for_each(|p| {
+            if prime[p] {
+                (p * p..limit).step_by(p).for_each(|m| prime[m] = false);
+            }
+        });
+        prime
+    }
+
+    fn mersenne(p: u32) -> UnsignedInteger {
+        &UnsignedInteger::from(2u8).pow(p) - &UnsignedInteger::from(1u8)
+    }
+
+    #[test]
+    fn test_primality_matches_sieve() {
+        sieve(20_000).iter().enumerate().for_each(|(x, &prime)| {
+            let x = UnsignedInteger::from(x);
+            assert_eq!(x.is_prime_trial_division(), Some(prime));
+            assert_eq!(x.miller_rabin(10), prime);
+            assert_eq!(x.bpsw(), prime);
+            assert_eq!(x.is_prime(), prime);
+        });
+    }
+    #[test]
+    fn test_trial_division() {
+        [
+            (1_000_000_007u64, true),
+            (999_999_937, true),
+            (999_999_937 * 3, false),
+            (1_000_003 * 1_000_033, false),
+            (4_294_967_291, true),
+        ]
+        .iter()
+        .for_each(|&(x, expected)| {
+            assert_eq!(
+                UnsignedInteger::from(x).is_prime_trial_division(),
+                Some(expected)
+            )
+        });
+        assert_eq!(
+            UnsignedInteger::from(u64::MAX as u128 + 1).is_prime_trial_division(),
+            None
+        );
+        //past the small primes, where the probabilistic tests do the work
+        (1_000_000_000_000u64..1_000_000_002_000).for_each(|x| {
+            let x = UnsignedInteger::from(x);
+            let expected = x.is_prime_trial_division().unwrap();
+            assert_eq!(x.bpsw(), expected);
+            assert_eq!(x.miller_rabin(3), expected);
+        });
+    }
+    #[test]
+    fn test_miller_rabin_zero_rounds() {
+        //no rounds still tries base two, so odd composites are not all called prime
+        [9u32, 15, 21, 25, 91, 561].iter().for_each(|&x| {
+            assert!(!UnsignedInteger::from(x).miller_rabin(0));
+        });
+        [3u32, 5, 7, 97, 7919].iter().for_each(|&x| {
+            assert!(UnsignedInteger::from(x).miller_rabin(0));
+        });
+        //2047 is a strong pseudoprime to base two, so one round is all that was run
+        assert!(UnsignedInteger::from(2047u16).miller_rabin(0));
+    }
+    #[test]
+    fn test_pseudoprimes() {
+        //2047 = 23 * 89 is a strong pseudoprime to base two, and 3215031751 to 2, 3, 5 and 7
+        assert!(UnsignedInteger::from(2047u16).miller_rabin(1));
+        assert!(!UnsignedInteger::from(2047u16).miller_rabin(2));
+        assert!(!UnsignedInteger::from(2047u16).bpsw());
+        let x = UnsignedInteger::from(3_215_031_751u32);
+        assert!(x.miller_rabin(4));
+        assert!(!x.miller_rabin(5));
+        assert!(!x.bpsw());
+        //Carmichael numbers and strong Lucas pseudoprimes
+        [
+            561u64,
+            41041,
+            825_265,
+            5459,
+            5777,
+            10877,
+            3_825_123_056_546_413_051,
+        ]
+        .iter()
+        .for_each(|&x| {
+            assert!(!UnsignedInteger::from(x).bpsw());
+            assert!(!UnsignedInteger::from(x).miller_rabin(13));
+        });
+        //squares of primes, which the Lucas test cannot handle on its own
+        let square = UnsignedInteger::from(1_000_000_007u64 * 1_000_000_007);
+        assert!(!square.bpsw());
+    }
+    #[test]
+    fn test_primality_large() {
+        [61u32, 89, 107, 127, 521, 607].iter().for_each(|&p| {
+            assert!(mersenne(p).bpsw());
+            assert!(mersenne(p).miller_rabin(5));
+        });
+        [67u32, 101, 257, 523].iter().for_each(|&p| {
+            assert!(!mersenne(p).bpsw());
+            assert!(!mersenne(p).miller_rabin(5));
+        });
+        //a product of two large primes, with the Lucas test needing to catch nothing
+        let product = &mersenne(89) * &mersenne(107);
+        assert!(!product.bpsw());
+        assert!(!product.miller_rabin(1));
+    }
+    #[test]
+    fn test_next_prev_prime() {
+        let prime = sieve(2000);
+        (0..1990usize).for_each(|x| {
+            let next = (x + 1..).find(|&p| prime[p]).unwrap();
+            let prev = (0..x).rev().find(|&p| prime[p]);
+            let big = UnsignedInteger::from(x);
+            assert_eq!(big.next_prime(), UnsignedInteger::from(next));
+            assert_eq!(big.prev_prime(), prev.map(UnsignedInteger::from));
+        });
+        let googol = UnsignedInteger::pow10(100);
+        assert_eq!(
+            googol.next_prime(),
+            &googol + &UnsignedInteger::from(267u16)
+        );
+        assert_eq!(
+            googol.prev_prime(),
+            Some(&googol - &UnsignedInteger::from(797u16))
+        );
+        assert_eq!(
+            mersenne(127).next_prime(),
+            &mersenne(127) + &UnsignedInteger::from(30u8)
+        );
+    }
+}
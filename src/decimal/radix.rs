@@ -0,0 +1,370 @@
+use super::{limbs, ParseUnsignedIntegerError, Symbol, UnsignedInteger};
+use std::fmt;
+
+fn check_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range 2 to 36, got {}",
+        radix
+    );
+}
+
+//the most digits of radix that fit in one machine word, and radix to that power
+fn chunk(radix: u32) -> (usize, u32) {
+    let mut digits = 1;
+    let mut power = radix as u64;
+    while power * radix as u64 <= u32::MAX as u64 {
+        power *= radix as u64;
+        digits += 1;
+    }
+    (digits, power as u32)
+}
+
+impl UnsignedInteger {
+    //digit values must be below radix and are given most significant first
+    pub(super) fn from_radix_digits(digits: &[u8], radix: u32) -> UnsignedInteger {
+        if radix == 10 {
+            let symbols = digits
+                .iter()
+                .rev()
+                .filter_map(|&d| Symbol::from_value(d))
+                .collect::<Vec<_>>();
+            UnsignedInteger::raw(&symbols)
+        } else {
+            let (size, _) = chunk(radix);
+            let limbs = digits.chunks(size).fold(Vec::new(), |mut acc, group| {
+                let value = group.iter().fold(0, |x, &d| x * radix + d as u32);
+                limbs::mul_small_assign(&mut acc, radix.pow(group.len() as u32));
+                limbs::add_small_assign(&mut acc, value);
+                acc
+            });
+            UnsignedInteger::from_limbs(limbs)
+        }
+    }
+
+    //panics if radix is not in the range 2 to 36, like the primitive from_str_radix
+    pub fn from_str_radix(
+        s: &str,
+        radix: u32,
+    ) -> Result<UnsignedInteger, ParseUnsignedIntegerError> {
+        check_radix(radix);
+        if s.is_empty() {
+            Err(ParseUnsignedIntegerError::EmptyString)
+        } else {
+            s.char_indices()
+                .map(|(position, c)| {
+                    c.to_digit(radix)
+                        .map(|d| d as u8)
+                        .ok_or_else(|| ParseUnsignedIntegerError::unexpected(position, c))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|digits| UnsignedInteger::from_radix_digits(&digits, radix))
+        }
+    }
+
+    //peels off whole chunks of digits of the given power, least significant first
+    fn radix_chunks(&self, power: u32) -> Vec<u32> {
+        let mut chunks = Vec::new();
+        let mut rest = limbs::normalise(self.limbs.clone());
+        while !rest.is_empty() {
+            let (quotient, remainder) = limbs::div_rem_small(&rest, power);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+        chunks
+    }
+
+    //lowercase digits with no prefix, so zero is "0" in every radix
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        check_radix(radix);
+        if radix == 10 {
+            self.to_string()
+        } else {
+            let (size, power) = chunk(radix);
+            let chunks = self.radix_chunks(power);
+            let to_digits = |mut x: u32, width: usize| {
+                let mut digits = Vec::with_capacity(width);
+                while x != 0 || digits.len() < width {
+                    digits.push(std::char::from_digit(x % radix, radix).unwrap());
+                    x /= radix;
+                }
+                digits.into_iter().rev().collect::<String>()
+            };
+            match chunks.split_last() {
+                None => "0".to_string(),
+                Some((top, lower)) => {
+                    //only the most significant chunk goes without its leading zeros
+                    lower
+                        .iter()
+                        .rev()
+                        .fold(to_digits(*top, 0), |acc, &x| acc + &to_digits(x, size))
+                }
+            }
+        }
+    }
+}
+
+//bytes are digits in radix 256, and the fewest of them are used, so zero is a single zero byte
+impl UnsignedInteger {
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let (size, power) = chunk(256);
+        let mut bytes = self
+            .radix_chunks(power)
+            .iter()
+            .flat_map(|chunk| {
+                chunk
+                    .to_le_bytes()
+                    .iter()
+                    .take(size)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        while bytes.len() > 1 && bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        if bytes.is_empty() {
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    //leading zero bytes are allowed, and no bytes at all is zero
+    pub fn from_bytes_be(bytes: &[u8]) -> UnsignedInteger {
+        UnsignedInteger::from_radix_digits(bytes, 256)
+    }
+
+    pub fn from_bytes_le(bytes: &[u8]) -> UnsignedInteger {
+        let bytes = bytes.iter().rev().cloned().collect::<Vec<_>>();
+        UnsignedInteger::from_bytes_be(&bytes)
+    }
+}
+
+impl fmt::Binary for UnsignedInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0b", &self.to_str_radix(2))
+    }
+}
+
+impl fmt::Octal for UnsignedInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0o", &self.to_str_radix(8))
+    }
+}
+
+impl fmt::LowerHex for UnsignedInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_str_radix(16))
+    }
+}
+
+impl fmt::UpperHex for UnsignedInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_str_radix(16).to_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const CASES: [u128; 12] = [
+        0,
+        1,
+        7,
+        35,
+        36,
+        255,
+        256,
+        4221,
+        999_999_999,
+        1_000_000_000,
+        u64::MAX as u128,
+        u128::MAX,
+    ];
+
+    fn u128_to_str_radix(mut x: u128, radix: u32) -> String {
+        let mut digits = Vec::new();
+        loop {
+            digits.push(std::char::from_digit((x % radix as u128) as u32, radix).unwrap());
+            x /= radix as u128;
+            if x == 0 {
+                break;
+            }
+        }
+        digits.into_iter().rev().collect()
+    }
+
+    #[test]
+    fn test_to_str_radix() {
+        (2..=36).for_each(|radix| {
+            CASES.iter().for_each(|&x| {
+                assert_eq!(
+                    UnsignedInteger::from(x).to_str_radix(radix),
+                    u128_to_str_radix(x, radix)
+                )
+            })
+        });
+    }
+    #[test]
+    fn test_from_str_radix() {
+        (2..=36).for_each(|radix| {
+            CASES.iter().for_each(|&x| {
+                let s = u128_to_str_radix(x, radix);
+                assert_eq!(
+                    UnsignedInteger::from_str_radix(&s, radix),
+                    Ok(UnsignedInteger::from(x))
+                );
+                assert_eq!(
+                    UnsignedInteger::from_str_radix(&s.to_uppercase(), radix),
+                    Ok(UnsignedInteger::from(x))
+                );
+            })
+        });
+    }
+    #[test]
+    fn test_radix_round_trip_large() {
+        let x = UnsignedInteger::from_str("6106370327537124369917353116076419185226836738092151283090256592406883297278429111051221535933437500").unwrap();
+        (2..=36).for_each(|radix| {
+            assert_eq!(
+                UnsignedInteger::from_str_radix(&x.to_str_radix(radix), radix),
+                Ok(x.clone())
+            )
+        });
+        let two_to_the_128 = UnsignedInteger::from(u128::MAX) + UnsignedInteger::from(1u8);
+        assert_eq!(
+            two_to_the_128.to_str_radix(16),
+            format!("1{}", "0".repeat(32))
+        );
+    }
+    #[test]
+    fn test_from_str_radix_leading_zeros() {
+        assert_eq!(
+            UnsignedInteger::from_str_radix("000ff", 16),
+            Ok(UnsignedInteger::from(255u8))
+        );
+        assert_eq!(
+            UnsignedInteger::from_str_radix("0", 2),
+            Ok(UnsignedInteger::from(0u8))
+        );
+    }
+    #[test]
+    fn test_from_str_radix_errors() {
+        assert_eq!(
+            UnsignedInteger::from_str_radix("", 16),
+            Err(ParseUnsignedIntegerError::EmptyString)
+        );
+        assert_eq!(
+            UnsignedInteger::from_str_radix("1778", 8),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 3,
+                character: '8'
+            })
+        );
+        assert_eq!(
+            UnsignedInteger::from_str_radix("0xff", 16),
+            Err(ParseUnsignedIntegerError::InvalidDigit {
+                position: 1,
+                character: 'x'
+            })
+        );
+        assert_eq!(
+            UnsignedInteger::from_str_radix("-ff", 16),
+            Err(ParseUnsignedIntegerError::UnexpectedSign {
+                position: 0,
+                character: '-'
+            })
+        );
+    }
+    #[test]
+    fn test_to_bytes() {
+        CASES.iter().for_each(|&x| {
+            let big = UnsignedInteger::from(x);
+            let significant = std::cmp::max(1, 16 - x.leading_zeros() as usize / 8);
+            assert_eq!(
+                big.to_bytes_be(),
+                x.to_be_bytes()[16 - significant..].to_vec()
+            );
+            assert_eq!(big.to_bytes_le(), x.to_le_bytes()[..significant].to_vec());
+        });
+    }
+    #[test]
+    fn test_from_bytes() {
+        CASES.iter().for_each(|&x| {
+            assert_eq!(
+                UnsignedInteger::from_bytes_be(&x.to_be_bytes()),
+                UnsignedInteger::from(x)
+            );
+            assert_eq!(
+                UnsignedInteger::from_bytes_le(&x.to_le_bytes()),
+                UnsignedInteger::from(x)
+            );
+        });
+        assert_eq!(
+            UnsignedInteger::from_bytes_be(&[]),
+            UnsignedInteger::from(0u8)
+        );
+        assert_eq!(
+            UnsignedInteger::from_bytes_le(&[]),
+            UnsignedInteger::from(0u8)
+        );
+        assert_eq!(
+            UnsignedInteger::from_bytes_be(&[0, 0, 1, 0]),
+            UnsignedInteger::from(256u16)
+        );
+        assert_eq!(
+            UnsignedInteger::from_bytes_le(&[0, 1, 0, 0]),
+            UnsignedInteger::from(256u16)
+        );
+    }
+    #[test]
+    fn test_bytes_round_trip_large() {
+        [
+            "6106370327537124369917353116076419185226836738092151283090256592406883297278429111051221535933437500",
+            "340282366920938463463374607431768211456",
+            &"9".repeat(500),
+        ]
+        .iter()
+        .for_each(|x| {
+            let big = UnsignedInteger::from_str(x).unwrap();
+            let be = big.to_bytes_be();
+            let le = big.to_bytes_le();
+            assert_ne!(be[0], 0);
+            assert_eq!(be.iter().rev().cloned().collect::<Vec<_>>(), le);
+            assert_eq!(format!("{}", UnsignedInteger::from_bytes_be(&be)), *x);
+            assert_eq!(format!("{}", UnsignedInteger::from_bytes_le(&le)), *x);
+        });
+        let two_to_the_128 = UnsignedInteger::from(u128::MAX) + UnsignedInteger::from(1u8);
+        let mut expected = vec![1];
+        expected.extend_from_slice(&[0; 16]);
+        assert_eq!(two_to_the_128.to_bytes_be(), expected);
+    }
+    #[test]
+    #[should_panic(expected = "radix must be in the range 2 to 36")]
+    fn test_from_str_radix_bad_radix() {
+        let _ = UnsignedInteger::from_str_radix("1", 37);
+    }
+    #[test]
+    fn test_format_traits() {
+        CASES.iter().for_each(|&x| {
+            let big = UnsignedInteger::from(x);
+            assert_eq!(format!("{:x}", big), format!("{:x}", x));
+            assert_eq!(format!("{:X}", big), format!("{:X}", x));
+            assert_eq!(format!("{:o}", big), format!("{:o}", x));
+            assert_eq!(format!("{:b}", big), format!("{:b}", x));
+            assert_eq!(format!("{:#x}", big), format!("{:#x}", x));
+            assert_eq!(format!("{:#X}", big), format!("{:#X}", x));
+            assert_eq!(format!("{:#o}", big), format!("{:#o}", x));
+            assert_eq!(format!("{:#b}", big), format!("{:#b}", x));
+            assert_eq!(format!("{:#018x}", big), format!("{:#018x}", x));
+            assert_eq!(format!("{:>12b}", big), format!("{:>12b}", x));
+        });
+    }
+}
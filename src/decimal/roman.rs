@@ -0,0 +1,408 @@
+use super::{limbs, UnsignedInteger};
+use std::fmt;
+
+//combining overline, the vinculum, multiplies the numeral before it by a thousand
+const VINCULUM: char = '\u{305}';
+//combining macron, which looks the same and is accepted when parsing leniently
+const MACRON: char = '\u{304}';
+
+#[derive(Debug)]
+enum Numeral {
+    I,
+    V,
+    X,
+    L,
+    C,
+    D,
+    M,
+}
+
+impl Numeral {
+    fn value(&self) -> u32 {
+        match self {
+            Numeral::I => 1,
+            Numeral::V => 5,
+            Numeral::X => 10,
+            Numeral::L => 50,
+            Numeral::C => 100,
+            Numeral::D => 500,
+            Numeral::M => 1000,
+        }
+    }
+
+    fn from_char(c: &char) -> Option<Numeral> {
+        match c {
+            'I' => Some(Numeral::I),
+            'V' => Some(Numeral::V),
+            'X' => Some(Numeral::X),
+            'L' => Some(Numeral::L),
+            'C' => Some(Numeral::C),
+            'D' => Some(Numeral::D),
+            'M' => Some(Numeral::M),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RomanMode {
+    Strict,  //only the canonical form, as produced by roman()
+    Lenient, //any additive or subtractive spelling, eg IIII or IC, in either case
+}
+
+impl Clone for RomanMode {
+    fn clone(&self) -> Self {
+        match self {
+            RomanMode::Strict => RomanMode::Strict,
+            RomanMode::Lenient => RomanMode::Lenient,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RomanNumeralError {
+    EmptyString,
+    //positions are byte offsets into the string being parsed
+    InvalidNumeral { position: usize, character: char },
+    //a valid numeral that is not spelled the canonical way, from the first byte that differs
+    NonCanonical { position: usize },
+}
+
+impl PartialEq for RomanNumeralError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RomanNumeralError::EmptyString, RomanNumeralError::EmptyString) => true,
+            (
+                RomanNumeralError::InvalidNumeral {
+                    position: a,
+                    character: x,
+                },
+                RomanNumeralError::InvalidNumeral {
+                    position: b,
+                    character: y,
+                },
+            ) => a == b && x == y,
+            (
+                RomanNumeralError::NonCanonical { position: a },
+                RomanNumeralError::NonCanonical { position: b },
+            ) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Clone for RomanNumeralError {
+    fn clone(&self) -> Self {
+        match self {
+            RomanNumeralError::EmptyString => RomanNumeralError::EmptyString,
+            RomanNumeralError::InvalidNumeral {
+                position,
+                character,
+            } => RomanNumeralError::InvalidNumeral {
+                position: *position,
+                character: *character,
+            },
+            RomanNumeralError::NonCanonical { position } => RomanNumeralError::NonCanonical {
+                position: *position,
+            },
+        }
+    }
+}
+
+impl fmt::Display for RomanNumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RomanNumeralError::EmptyString => {
+                write!(f, "cannot parse roman numeral from empty string")
+            }
+            RomanNumeralError::InvalidNumeral {
+                position,
+                character,
+            } => write!(
+                f,
+                "invalid roman numeral {:?} at byte {}",
+                character, position
+            ),
+            RomanNumeralError::NonCanonical { position } => write!(
+                f,
+                "roman numeral is not in canonical form from byte {}",
+                position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RomanNumeralError {}
+
+//below 4000, each numeral followed by the given number of vinculums
+fn roman_small(mut x: u32, vinculums: usize) -> String {
+    let table = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut numerals = String::new();
+    for (value, spelling) in table.iter() {
+        while x >= *value {
+            numerals.push_str(spelling);
+            x -= value;
+        }
+    }
+    numerals
+        .chars()
+        .flat_map(|c| std::iter::once(c).chain(std::iter::repeat(VINCULUM).take(vinculums)))
+        .collect()
+}
+
+//from 4000 up the thousands are written once more overlined and the rest as usual, so each
+//group of three digits, taken straight from the limbs, has one vinculum more than the last
+fn roman_digits(x: &UnsignedInteger) -> String {
+    let mut groups = limbs::significant(&x.limbs)
+        .iter()
+        .flat_map(|&limb| (0..3).map(move |i| limb / limbs::POWERS_OF_TEN[3 * i] % 1000))
+        .collect::<Vec<_>>();
+    while groups.last() == Some(&0) {
+        groups.pop();
+    }
+    //the top group takes in the one below while that stays under 4000
+    let top = match groups.pop() {
+        None => 0,
+        Some(top) if top < 4 && !groups.is_empty() => top * 1000 + groups.pop().unwrap(),
+        Some(top) => top,
+    };
+    let n = groups.len();
+    std::iter::once(roman_small(top, n))
+        .chain(
+            groups
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, &group)| roman_small(group, n - 1 - i)),
+        )
+        .collect()
+}
+
+//writes an UnsignedInteger as Roman numerals through Display, with N for zero
+#[derive(Debug)]
+pub struct Roman<'a> {
+    value: &'a UnsignedInteger,
+}
+
+impl UnsignedInteger {
+    pub fn roman(&self) -> Roman<'_> {
+        Roman { value: self }
+    }
+
+    pub fn from_roman(s: &str, mode: RomanMode) -> Result<UnsignedInteger, RomanNumeralError> {
+        let lenient = matches!(mode, RomanMode::Lenient);
+        //each numeral with the number of vinculums over it
+        let mut numerals: Vec<(Numeral, usize)> = Vec::new();
+        for (position, c) in s.char_indices() {
+            let invalid = RomanNumeralError::InvalidNumeral {
+                position,
+                character: c,
+            };
+            if c == VINCULUM || (lenient && c == MACRON) {
+                numerals.last_mut().ok_or(invalid)?.1 += 1;
+            } else if c == 'N' && s.len() == 1 {
+                return Ok(UnsignedInteger::zero());
+            } else {
+                let c = if lenient { c.to_ascii_uppercase() } else { c };
+                numerals.push((Numeral::from_char(&c).ok_or(invalid)?, 0));
+            }
+        }
+        if numerals.is_empty() {
+            return Err(RomanNumeralError::EmptyString);
+        }
+        let values = numerals
+            .iter()
+            .map(|(numeral, vinculums)| {
+                UnsignedInteger::from(numeral.value()).shift_digits_up(3 * vinculums)
+            })
+            .collect::<Vec<_>>();
+        //a numeral smaller than the one after it is subtracted
+        let mut added = UnsignedInteger::zero();
+        let mut subtracted = UnsignedInteger::zero();
+        for (i, value) in values.iter().enumerate() {
+            if values.get(i + 1).map_or(false, |next| value < next) {
+                subtracted += value;
+            } else {
+                added += value;
+            }
+        }
+        let value = added - subtracted;
+        if !lenient {
+            let canonical = format!("{}", value.roman());
+            if canonical != s {
+                let position = canonical
+                    .char_indices()
+                    .zip(s.chars())
+                    .find(|((_, expected), c)| expected != c)
+                    .map_or(std::cmp::min(canonical.len(), s.len()), |((i, _), _)| i);
+                return Err(RomanNumeralError::NonCanonical { position });
+            }
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for Roman<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value.is_zero() {
+            f.pad("N")
+        } else {
+            f.pad(&roman_digits(self.value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roman(x: u32) -> String {
+        format!("{}", UnsignedInteger::from(x).roman())
+    }
+
+    #[test]
+    fn test_roman() {
+        [
+            (0, "N"),
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (90, "XC"),
+            (400, "CD"),
+            (1994, "MCMXCIV"),
+            (2024, "MMXXIV"),
+            (3999, "MMMCMXCIX"),
+            (4000, "I\u{305}V\u{305}"),
+            (4500, "I\u{305}V\u{305}D"),
+            (13000, "X\u{305}I\u{305}I\u{305}I\u{305}"),
+            (1_000_000, "M\u{305}"),
+            (4_000_000, "I\u{305}\u{305}V\u{305}\u{305}"),
+            (
+                4_004_004,
+                "I\u{305}\u{305}V\u{305}\u{305}I\u{305}V\u{305}IV",
+            ),
+        ]
+        .iter()
+        .for_each(|(x, expected)| assert_eq!(roman(*x), *expected));
+    }
+    #[test]
+    fn test_roman_round_trip() {
+        (0..5000)
+            .chain((0..200).map(|x| x * 7919 + 5000))
+            .for_each(|x| {
+                assert_eq!(
+                    UnsignedInteger::from_roman(&roman(x), RomanMode::Strict),
+                    Ok(UnsignedInteger::from(x))
+                );
+                assert_eq!(
+                    UnsignedInteger::from_roman(&roman(x), RomanMode::Lenient),
+                    Ok(UnsignedInteger::from(x))
+                );
+            });
+        //groups spread over several limbs, some of them zero
+        [
+            123_456_789_012u128,
+            3_999_000_000_001,
+            4_000_000_000_000_000_000,
+            10u128.pow(30),
+        ]
+        .iter()
+        .map(|&x| UnsignedInteger::from(x))
+        .for_each(|big| {
+            assert_eq!(
+                UnsignedInteger::from_roman(&format!("{}", big.roman()), RomanMode::Strict),
+                Ok(big)
+            )
+        });
+    }
+    #[test]
+    fn test_roman_lenient() {
+        [
+            ("IIII", 4),
+            ("MDCCCCX", 1910),
+            ("IC", 99),
+            ("iv", 4),
+            ("mmxxiv", 2024),
+            ("I\u{304}V\u{304}", 4000),
+        ]
+        .iter()
+        .for_each(|(s, expected)| {
+            assert_eq!(
+                UnsignedInteger::from_roman(s, RomanMode::Lenient),
+                Ok(UnsignedInteger::from(*expected as u32))
+            )
+        });
+    }
+    #[test]
+    fn test_roman_strict_errors() {
+        [("IIII", 1), ("IC", 0), ("MMMM", 0), ("XIIX", 1), ("VV", 0)]
+            .iter()
+            .for_each(|(s, position)| {
+                assert_eq!(
+                    UnsignedInteger::from_roman(s, RomanMode::Strict),
+                    Err(RomanNumeralError::NonCanonical {
+                        position: *position
+                    })
+                )
+            });
+        //strict mode only takes capitals
+        assert_eq!(
+            UnsignedInteger::from_roman("iv", RomanMode::Strict),
+            Err(RomanNumeralError::InvalidNumeral {
+                position: 0,
+                character: 'i'
+            })
+        );
+    }
+    #[test]
+    fn test_roman_invalid() {
+        [RomanMode::Strict, RomanMode::Lenient]
+            .iter()
+            .for_each(|mode| {
+                assert_eq!(
+                    UnsignedInteger::from_roman("", mode.clone()),
+                    Err(RomanNumeralError::EmptyString)
+                );
+                [
+                    ("MCMZ", 3, 'Z'),
+                    ("\u{305}I", 0, '\u{305}'),
+                    ("X I", 1, ' '),
+                    ("NN", 0, 'N'),
+                    ("4", 0, '4'),
+                ]
+                .iter()
+                .for_each(|(s, position, character)| {
+                    assert_eq!(
+                        UnsignedInteger::from_roman(s, mode.clone()),
+                        Err(RomanNumeralError::InvalidNumeral {
+                            position: *position,
+                            character: *character
+                        })
+                    )
+                });
+            });
+    }
+    #[test]
+    fn test_roman_formatter_flags() {
+        assert_eq!(
+            format!("{:>6}", UnsignedInteger::from(14u32).roman()),
+            "   XIV"
+        );
+    }
+}
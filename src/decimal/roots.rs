@@ -0,0 +1,173 @@
+use super::UnsignedInteger;
+use std::convert::TryFrom;
+
+impl UnsignedInteger {
+    //close to the kth root, from the leading digits as a float
+    fn root_estimate(&self, k: u32) -> UnsignedInteger {
+        const LEADING: usize = 15;
+        let dropped = self.digit_count().saturating_sub(LEADING);
+        let leading = u64::try_from(&self.shr10(dropped)).unwrap() as f64;
+        let log = (leading.log10() + dropped as f64) / k as f64;
+        //nudged up past any rounding in the float, since Newton's method is slow to climb
+        //back from below when k is large
+        let mantissa = 10f64.powf(log.fract()) * 10f64.powi(LEADING as i32) * (1.0 + 1e-9);
+        let estimate = UnsignedInteger::from(mantissa as u64);
+        let whole = log.trunc() as usize;
+        let estimate = if whole >= LEADING {
+            estimate.shl10(whole - LEADING)
+        } else {
+            estimate.shr10(LEADING - whole)
+        };
+        estimate + UnsignedInteger::from(1u8)
+    }
+
+    //floor of the kth root and what is left over, so self is root^k + remainder
+    //panics if k is zero
+    pub fn nth_root_rem(&self, k: u32) -> (UnsignedInteger, UnsignedInteger) {
+        assert!(k != 0, "attempt to take a root of degree zero");
+        let root = if self.is_zero() || k == 1 {
+            self.clone()
+        } else if k as usize > self.digit_count() * 10 / 3 {
+            //self is below 10^digits, which is below 2^k, so the root is 1
+            UnsignedInteger::from(1u8)
+        } else {
+            let (big_k, k_less_one) = (UnsignedInteger::from(k), UnsignedInteger::from(k - 1));
+            let newton =
+                |x: &UnsignedInteger| &(&(&k_less_one * x) + &(self / &x.pow(k - 1))) / &big_k;
+            //one step from anywhere above zero lands at or above the root, and from there
+            //each step comes down until it reaches the floor of the root
+            let mut x = newton(&self.root_estimate(k));
+            loop {
+                let next = newton(&x);
+                if next >= x {
+                    break x;
+                }
+                x = next;
+            }
+        };
+        let remainder = self - &root.pow(k);
+        (root, remainder)
+    }
+
+    pub fn nth_root(&self, k: u32) -> UnsignedInteger {
+        self.nth_root_rem(k).0
+    }
+
+    pub fn sqrt_rem(&self) -> (UnsignedInteger, UnsignedInteger) {
+        self.nth_root_rem(2)
+    }
+
+    pub fn isqrt(&self) -> UnsignedInteger {
+        self.nth_root(2)
+    }
+
+    pub fn is_perfect_square(&self) -> bool {
+        self.sqrt_rem().1.is_zero()
+    }
+
+    //whether self is b^k for some b and some k of at least two, which includes zero and one
+    pub fn is_perfect_power(&self) -> bool {
+        //any power is a prime power of something, and 2^k > self bounds the exponents worth trying
+        let bound = (self.digit_count() * 10 / 3 + 1) as u32;
+        self <= &UnsignedInteger::from(1u8)
+            || (2..bound)
+                .filter(|k| (2..*k).take_while(|d| d * d <= *k).all(|d| k % d != 0))
+                .any(|k| self.nth_root_rem(k).1.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::big;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_nth_root_matches_primitive() {
+        let cases = (0..2000u128).chain(
+            [
+                999_999_999,
+                1_000_000_000,
+                123_456_789_012_345,
+                u64::MAX as u128,
+                u128::MAX,
+            ]
+            .iter()
+            .cloned(),
+        );
+        cases.for_each(|x| {
+            (1..=9)
+                .chain([31, 64, 127, 128, 200].iter().cloned())
+                .for_each(|k| {
+                    let (root, remainder) = UnsignedInteger::from(x).nth_root_rem(k);
+                    let r = u128::try_from(&root).unwrap();
+                    //root^k <= x < (root + 1)^k
+                    assert_eq!(
+                        r.checked_pow(k).map(|p| x - p),
+                        Some(u128::try_from(&remainder).unwrap())
+                    );
+                    assert!(r
+                        .checked_add(1)
+                        .and_then(|r| r.checked_pow(k))
+                        .map_or(true, |p| p > x));
+                })
+        });
+    }
+    #[test]
+    fn test_sqrt_rem_large() {
+        let x = UnsignedInteger::pow10(100) + UnsignedInteger::from(7u8);
+        let square = &x * &x;
+        assert_eq!(square.sqrt_rem(), (x.clone(), UnsignedInteger::from(0u8)));
+        let one_less = &square - &UnsignedInteger::from(1u8);
+        let below = &x - &UnsignedInteger::from(1u8);
+        assert_eq!(one_less.sqrt_rem(), (below.clone(), &below + &below));
+        assert_eq!(big(&"9".repeat(301)).isqrt().digit_count(), 151);
+    }
+    #[test]
+    fn test_nth_root_large() {
+        let x = UnsignedInteger::from(7u8).pow(1000);
+        assert_eq!(x.nth_root(1000), UnsignedInteger::from(7u8));
+        assert_eq!(x.nth_root(500), UnsignedInteger::from(49u8));
+        assert_eq!(x.nth_root(4), UnsignedInteger::from(7u8).pow(250));
+        assert_eq!(x.nth_root(8), UnsignedInteger::from(7u8).pow(125));
+        assert_eq!(x.nth_root(1001), UnsignedInteger::from(6u8));
+        assert_eq!(x.nth_root(100_000), UnsignedInteger::from(1u8));
+        let (root, remainder) = (&x + &UnsignedInteger::from(5u8)).nth_root_rem(250);
+        assert_eq!(root, UnsignedInteger::from(2401u16));
+        assert_eq!(remainder, UnsignedInteger::from(5u8));
+    }
+    #[test]
+    #[should_panic(expected = "attempt to take a root of degree zero")]
+    fn test_nth_root_degree_zero() {
+        let _ = UnsignedInteger::from(8u8).nth_root(0);
+    }
+    #[test]
+    fn test_is_perfect_square() {
+        (0..1000u32).for_each(|x| {
+            let expected = (0..=x).take_while(|r| r * r <= x).any(|r| r * r == x);
+            assert_eq!(UnsignedInteger::from(x).is_perfect_square(), expected);
+        });
+        let x = big(&"1234567890".repeat(8));
+        assert!((&x * &x).is_perfect_square());
+        assert!(!(&(&x * &x) + &UnsignedInteger::from(1u8)).is_perfect_square());
+    }
+    #[test]
+    fn test_is_perfect_power() {
+        let powers = (2..71u32)
+            .flat_map(|b| (2..13).filter_map(move |k| b.checked_pow(k)))
+            .filter(|&x| x < 5000)
+            .collect::<Vec<_>>();
+        (0..5000u32).for_each(|x| {
+            assert_eq!(
+                UnsignedInteger::from(x).is_perfect_power(),
+                x <= 1 || powers.contains(&x)
+            )
+        });
+        let x = UnsignedInteger::from(7u8).pow(1000);
+        assert!(x.is_perfect_power());
+        assert!(!(&x + &UnsignedInteger::from(1u8)).is_perfect_power());
+        assert!(UnsignedInteger::from(u64::MAX as u128 + 1).is_perfect_power());
+        assert!(UnsignedInteger::from(3u8).pow(101).is_perfect_power());
+        assert!(!UnsignedInteger::from(u64::MAX).is_perfect_power());
+    }
+}
@@ -0,0 +1,260 @@
+use super::UnsignedInteger;
+use std::fmt;
+use std::str::FromStr;
+
+//how the digits dropped by a precision are rounded into the ones kept
+#[derive(Debug)]
+pub enum Rounding {
+    Down,     //truncate
+    HalfUp,   //ties away from zero
+    HalfEven, //ties to an even last digit, as the primitive integer types do
+    Up,       //any dropped digit rounds up
+}
+
+impl Clone for Rounding {
+    fn clone(&self) -> Self {
+        match self {
+            Rounding::Down => Rounding::Down,
+            Rounding::HalfUp => Rounding::HalfUp,
+            Rounding::HalfEven => Rounding::HalfEven,
+            Rounding::Up => Rounding::Up,
+        }
+    }
+}
+
+impl Rounding {
+    //whether kept, followed by the dropped digits, should be rounded up
+    fn rounds_up(&self, kept: &str, dropped: &str) -> bool {
+        let nonzero = |digits: &str| digits.chars().any(|c| c != '0');
+        let first = dropped.chars().next().unwrap_or('0');
+        match self {
+            Rounding::Down => false,
+            Rounding::Up => nonzero(dropped),
+            Rounding::HalfUp => first >= '5',
+            Rounding::HalfEven => {
+                let odd = kept
+                    .chars()
+                    .last()
+                    .map_or(false, |c| c.to_digit(10).unwrap() % 2 == 1);
+                first > '5' || (first == '5' && (nonzero(&dropped[1..]) || odd))
+            }
+        }
+    }
+}
+
+//formats an UnsignedInteger as mantissa and exponent through LowerExp and UpperExp
+#[derive(Debug)]
+pub struct Scientific<'a> {
+    value: &'a UnsignedInteger,
+    rounding: Rounding,
+    engineering: bool,
+}
+
+impl UnsignedInteger {
+    pub fn scientific(&self) -> Scientific<'_> {
+        Scientific {
+            value: self,
+            rounding: Rounding::HalfEven,
+            engineering: false,
+        }
+    }
+}
+
+impl Scientific<'_> {
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    //keeps the exponent a multiple of three, with one to three digits before the point
+    pub fn engineering(mut self) -> Self {
+        self.engineering = true;
+        self
+    }
+
+    fn exponent(&self, len: usize) -> usize {
+        if self.engineering {
+            (len - 1) / 3 * 3
+        } else {
+            len - 1
+        }
+    }
+
+    //the mantissa digits before and after the point, and the exponent
+    fn parts(&self, precision: Option<usize>) -> (String, String, usize) {
+        let digits = self.value.to_decimal_string();
+        let exponent = self.exponent(digits.len());
+        let whole = digits.len() - exponent;
+        match precision {
+            None => {
+                //exact, with no trailing zeros after the point
+                let (before, after) = digits.split_at(whole);
+                let after = after.trim_end_matches('0');
+                (before.to_string(), after.to_string(), exponent)
+            }
+            Some(precision) if whole + precision >= digits.len() => {
+                let padding = "0".repeat(whole + precision - digits.len());
+                let (before, after) = digits.split_at(whole);
+                (before.to_string(), after.to_string() + &padding, exponent)
+            }
+            Some(precision) => {
+                let (kept, dropped) = digits.split_at(whole + precision);
+                let kept = if self.rounding.rounds_up(kept, dropped) {
+                    let mut rounded = UnsignedInteger::from_str(kept).unwrap();
+                    rounded += UnsignedInteger::from_u128(1);
+                    rounded.to_decimal_string()
+                } else {
+                    kept.to_string()
+                };
+                if kept.len() > whole + precision {
+                    //rounding carried into a new digit, so the value is now a power of ten
+                    let exponent = self.exponent(digits.len() + 1);
+                    let whole = digits.len() + 1 - exponent;
+                    (
+                        "1".to_string() + &"0".repeat(whole - 1),
+                        "0".repeat(precision),
+                        exponent,
+                    )
+                } else {
+                    let (before, after) = kept.split_at(whole);
+                    (before.to_string(), after.to_string(), exponent)
+                }
+            }
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, e: char) -> fmt::Result {
+        let (before, after, exponent) = self.parts(f.precision());
+        let mantissa = if after.is_empty() {
+            before
+        } else {
+            format!("{}.{}", before, after)
+        };
+        f.pad_integral(true, "", &format!("{}{}{}", mantissa, e, exponent))
+    }
+}
+
+impl fmt::LowerExp for Scientific<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 'e')
+    }
+}
+
+impl fmt::UpperExp for Scientific<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 'E')
+    }
+}
+
+impl fmt::LowerExp for UnsignedInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.scientific(), f)
+    }
+}
+
+impl fmt::UpperExp for UnsignedInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.scientific(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::big;
+
+    #[test]
+    fn test_exp_matches_primitive() {
+        let cases = [
+            0u128,
+            1,
+            5,
+            15,
+            25,
+            1000,
+            1234,
+            1250,
+            99999,
+            610_600,
+            123_456_789,
+            999_999_999_999,
+            u64::MAX as u128,
+            u128::MAX,
+        ];
+        cases.iter().for_each(|&x| {
+            let y = UnsignedInteger::from_u128(x);
+            assert_eq!(format!("{:e}", y), format!("{:e}", x));
+            assert_eq!(format!("{:E}", y), format!("{:E}", x));
+            (0..6).for_each(|precision| {
+                assert_eq!(
+                    format!("{:.*e}", precision, y),
+                    format!("{:.*e}", precision, x)
+                )
+            });
+            assert_eq!(format!("{:>+16.2e}", y), format!("{:>+16.2e}", x));
+            assert_eq!(format!("{:016.3E}", y), format!("{:016.3E}", x));
+        });
+    }
+    #[test]
+    fn test_exp_large() {
+        let x = big(&("6106".to_string() + &"0".repeat(96)));
+        assert_eq!(format!("{:e}", x), "6.106e99");
+        assert_eq!(format!("{:.1e}", x), "6.1e99");
+        assert_eq!(format!("{:.5E}", x), "6.10600E99");
+        assert_eq!(format!("{:e}", x.scientific().engineering()), "6.106e99");
+        let y = big(&("6106".to_string() + &"0".repeat(95)));
+        assert_eq!(format!("{:e}", y.scientific().engineering()), "610.6e96");
+        let nines = big(&"9".repeat(200));
+        assert_eq!(format!("{:.3e}", nines), "1.000e200");
+    }
+    #[test]
+    fn test_exp_rounding() {
+        let cases = [
+            ("1250", "1.2e3", "1.3e3", "1.2e3", "1.3e3"),
+            ("1350", "1.3e3", "1.4e3", "1.4e3", "1.4e3"),
+            ("1251", "1.2e3", "1.3e3", "1.3e3", "1.3e3"),
+            ("1249", "1.2e3", "1.2e3", "1.2e3", "1.3e3"),
+            ("1200", "1.2e3", "1.2e3", "1.2e3", "1.2e3"),
+            ("9960", "9.9e3", "1.0e4", "1.0e4", "1.0e4"),
+        ];
+        cases.iter().for_each(|(x, down, half_up, half_even, up)| {
+            let x = big(x);
+            [
+                (Rounding::Down, down),
+                (Rounding::HalfUp, half_up),
+                (Rounding::HalfEven, half_even),
+                (Rounding::Up, up),
+            ]
+            .iter()
+            .for_each(|(rounding, expected)| {
+                assert_eq!(
+                    format!("{:.1e}", x.scientific().rounding(rounding.clone())),
+                    **expected
+                )
+            });
+        });
+    }
+    #[test]
+    fn test_engineering() {
+        [
+            ("0", "0e0", "0.00e0"),
+            ("7", "7e0", "7.00e0"),
+            ("1234", "1.234e3", "1.23e3"),
+            ("12345", "12.345e3", "12.34e3"),
+            ("123456", "123.456e3", "123.46e3"),
+            ("1234567", "1.234567e6", "1.23e6"),
+            ("999999", "999.999e3", "1.00e6"),
+            ("99999", "99.999e3", "100.00e3"),
+        ]
+        .iter()
+        .for_each(|(x, exact, rounded)| {
+            let x = big(x);
+            assert_eq!(format!("{:e}", x.scientific().engineering()), *exact);
+            assert_eq!(format!("{:.2e}", x.scientific().engineering()), *rounded);
+        });
+        assert_eq!(
+            format!("{:>12.1E}", big("45678").scientific().engineering()),
+            "      45.7E3"
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use super::UnsignedInteger;
+use serde::de::{self, MapAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+//serde_json with arbitrary_precision hands over numbers as a map with this single key,
+//whose value is the number exactly as it was written, the key is private to serde_json
+//and was checked against 1.0.154, so recheck it when moving to a new serde_json
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+//written as a decimal string, so no format has to fit it in a fixed size number
+impl Serialize for UnsignedInteger {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_decimal_string())
+    }
+}
+
+struct UnsignedIntegerVisitor;
+
+impl<'de> Visitor<'de> for UnsignedIntegerVisitor {
+    type Value = UnsignedInteger;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an unsigned integer or a string of decimal digits")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<UnsignedInteger, E> {
+        UnsignedInteger::from_str(s).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, x: u64) -> Result<UnsignedInteger, E> {
+        Ok(UnsignedInteger::from(x))
+    }
+
+    fn visit_u128<E: de::Error>(self, x: u128) -> Result<UnsignedInteger, E> {
+        Ok(UnsignedInteger::from(x))
+    }
+
+    fn visit_i64<E: de::Error>(self, x: i64) -> Result<UnsignedInteger, E> {
+        UnsignedInteger::try_from(x).map_err(|_| E::invalid_value(Unexpected::Signed(x), &self))
+    }
+
+    //without serde_json's arbitrary_precision, numbers above u64::MAX arrive as floats, which
+    //have already lost digits, so refuse them rather than round
+    fn visit_f64<E: de::Error>(self, x: f64) -> Result<UnsignedInteger, E> {
+        Err(E::custom(format!(
+            "floating point number {} cannot be read exactly, write it as a string or enable \
+             serde_json's arbitrary_precision feature",
+            x
+        )))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<UnsignedInteger, A::Error> {
+        match map.next_key::<String>()? {
+            Some(key) if key == NUMBER_TOKEN => {
+                let digits = map.next_value::<String>()?;
+                UnsignedInteger::from_str(&digits).map_err(de::Error::custom)
+            }
+            _ => Err(de::Error::invalid_type(Unexpected::Map, &self)),
+        }
+    }
+}
+
+//floats are refused rather than rounded, and long numbers never go through f64
+impl<'de> Deserialize<'de> for UnsignedInteger {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(UnsignedIntegerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_json(json: &str) -> Result<UnsignedInteger, String> {
+        serde_json::from_str::<UnsignedInteger>(json).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_serialise_as_string() {
+        let x = UnsignedInteger::from_str("123456789012345678901234567890").unwrap();
+        assert_eq!(
+            serde_json::to_string(&x).unwrap(),
+            "\"123456789012345678901234567890\""
+        );
+        assert_eq!(
+            serde_json::to_string(&UnsignedInteger::zero()).unwrap(),
+            "\"0\""
+        );
+    }
+    #[test]
+    fn test_deserialise() {
+        let long = "9".repeat(200);
+        [
+            ("\"0\"", "0"),
+            ("\"00123\"", "123"),
+            ("123", "123"),
+            ("18446744073709551615", "18446744073709551615"),
+            (
+                "340282366920938463463374607431768211456",
+                "340282366920938463463374607431768211456",
+            ),
+            (&format!("\"{}\"", long), &long),
+            (&long, &long),
+        ]
+        .iter()
+        .for_each(|(json, expected)| {
+            assert_eq!(
+                from_json(json),
+                Ok(UnsignedInteger::from_str(expected).unwrap())
+            )
+        });
+    }
+    #[test]
+    fn test_round_trip() {
+        ["0", "1", "1000000000", &"1234567890".repeat(30)]
+            .iter()
+            .for_each(|x| {
+                let x = UnsignedInteger::from_str(x).unwrap();
+                let json = serde_json::to_string(&x).unwrap();
+                assert_eq!(from_json(&json), Ok(x));
+            });
+    }
+    #[test]
+    fn test_deserialise_errors() {
+        assert!(from_json("\"12x4\"")
+            .unwrap_err()
+            .contains("invalid digit 'x' at byte 2"));
+        assert!(from_json("\"-5\"")
+            .unwrap_err()
+            .contains("unexpected sign '-' at byte 0"));
+        assert!(from_json("\"\"")
+            .unwrap_err()
+            .contains("cannot parse integer from empty string"));
+        //neither floats nor negatives get through as numbers
+        assert!(from_json("-5")
+            .unwrap_err()
+            .contains("invalid value: integer `-5`"));
+        assert!(from_json(&format!("-{}", "9".repeat(40)))
+            .unwrap_err()
+            .contains("unexpected sign '-' at byte 0"));
+        assert!(from_json("1.5")
+            .unwrap_err()
+            .contains("invalid digit '.' at byte 1"));
+        assert!(from_json("1e3").is_err());
+        assert!(from_json("[1]").is_err());
+        assert!(from_json("{\"a\": 1}").is_err());
+    }
+    #[test]
+    fn test_deserialise_without_arbitrary_precision() {
+        //other deserializers hand over plain primitives, as serde_json does without the feature
+        use serde::de::value::Error;
+        use serde::de::IntoDeserializer;
+        let from = |d: Result<UnsignedInteger, Error>| d.map_err(|e| e.to_string());
+        assert_eq!(
+            from(UnsignedInteger::deserialize(u64::MAX.into_deserializer())),
+            Ok(UnsignedInteger::from(u64::MAX))
+        );
+        assert_eq!(
+            from(UnsignedInteger::deserialize(u128::MAX.into_deserializer())),
+            Ok(UnsignedInteger::from(u128::MAX))
+        );
+        assert_eq!(
+            from(UnsignedInteger::deserialize("00123".into_deserializer())),
+            Ok(UnsignedInteger::from(123u8))
+        );
+        assert!(
+            from(UnsignedInteger::deserialize((-1i64).into_deserializer()))
+                .unwrap_err()
+                .contains("invalid value: integer `-1`")
+        );
+        assert!(
+            from(UnsignedInteger::deserialize(1e30f64.into_deserializer()))
+                .unwrap_err()
+                .contains("enable serde_json's arbitrary_precision feature")
+        );
+    }
+    #[test]
+    fn test_deserialise_toml() {
+        use std::collections::BTreeMap;
+        let from_toml = |s: &str| {
+            toml::from_str::<BTreeMap<String, UnsignedInteger>>(s).map_err(|e| e.to_string())
+        };
+        let long = "9".repeat(200);
+        let parsed = from_toml(&format!("small = 42\nlong = \"{}\"", long)).unwrap();
+        assert_eq!(parsed["small"], UnsignedInteger::from(42u8));
+        assert_eq!(parsed["long"], UnsignedInteger::from_str(&long).unwrap());
+        //TOML integers stop at i64, so anything longer has to be written as a string
+        assert!(from_toml("x = -5").is_err());
+        assert!(from_toml(&format!("x = {}", long)).is_err());
+        assert!(from_toml("x = 1.5").is_err());
+    }
+}
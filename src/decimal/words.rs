@@ -0,0 +1,413 @@
+use super::{Symbol, UnsignedInteger};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Scale {
+    Short, //million, billion, trillion, each a thousand times the last
+    Long,  //million, milliard, billion, billiard, each a thousand times the last
+}
+
+impl Clone for Scale {
+    fn clone(&self) -> Self {
+        match self {
+            Scale::Short => Scale::Short,
+            Scale::Long => Scale::Long,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Dialect {
+    British,  //one thousand two hundred and five
+    American, //one thousand two hundred five
+}
+
+impl Clone for Dialect {
+    fn clone(&self) -> Self {
+        match self {
+            Dialect::British => Dialect::British,
+            Dialect::American => Dialect::American,
+        }
+    }
+}
+
+impl Symbol {
+    fn word(&self) -> &'static str {
+        match self {
+            Symbol::Zero => "zero",
+            Symbol::One => "one",
+            Symbol::Two => "two",
+            Symbol::Three => "three",
+            Symbol::Four => "four",
+            Symbol::Five => "five",
+            Symbol::Six => "six",
+            Symbol::Seven => "seven",
+            Symbol::Eight => "eight",
+            Symbol::Nine => "nine",
+        }
+    }
+
+    //ten plus this digit
+    fn teen_word(&self) -> &'static str {
+        match self {
+            Symbol::Zero => "ten",
+            Symbol::One => "eleven",
+            Symbol::Two => "twelve",
+            Symbol::Three => "thirteen",
+            Symbol::Four => "fourteen",
+            Symbol::Five => "fifteen",
+            Symbol::Six => "sixteen",
+            Symbol::Seven => "seventeen",
+            Symbol::Eight => "eighteen",
+            Symbol::Nine => "nineteen",
+        }
+    }
+
+    //this digit in the tens place, from twenty up
+    fn tens_word(&self) -> &'static str {
+        match self {
+            Symbol::Zero | Symbol::One => "",
+            Symbol::Two => "twenty",
+            Symbol::Three => "thirty",
+            Symbol::Four => "forty",
+            Symbol::Five => "fifty",
+            Symbol::Six => "sixty",
+            Symbol::Seven => "seventy",
+            Symbol::Eight => "eighty",
+            Symbol::Nine => "ninety",
+        }
+    }
+
+    //latin prefixes for naming the -illions, following Conway and Wechsler
+    //the tens and hundreds come with the letters they let the units before them take
+    fn latin_small(&self) -> &'static str {
+        match self {
+            Symbol::Zero => "n",
+            Symbol::One => "m",
+            Symbol::Two => "b",
+            Symbol::Three => "tr",
+            Symbol::Four => "quadr",
+            Symbol::Five => "quint",
+            Symbol::Six => "sext",
+            Symbol::Seven => "sept",
+            Symbol::Eight => "oct",
+            Symbol::Nine => "non",
+        }
+    }
+
+    fn latin_units(&self, marks: &str) -> String {
+        let mark = |options: &str| {
+            marks
+                .chars()
+                .find(|c| options.contains(*c))
+                .map_or(String::new(), |c| c.to_string())
+        };
+        match self {
+            Symbol::Zero => String::new(),
+            Symbol::One => "un".to_string(),
+            Symbol::Two => "duo".to_string(),
+            Symbol::Three => format!("tre{}", mark("sx").replace('x', "s")),
+            Symbol::Four => "quattuor".to_string(),
+            Symbol::Five => "quin".to_string(),
+            Symbol::Six => format!("se{}", mark("sx")),
+            Symbol::Seven => format!("septe{}", mark("mn")),
+            Symbol::Eight => "octo".to_string(),
+            Symbol::Nine => format!("nove{}", mark("mn")),
+        }
+    }
+
+    fn latin_tens(&self) -> (&'static str, &'static str) {
+        match self {
+            Symbol::Zero => ("", ""),
+            Symbol::One => ("deci", "n"),
+            Symbol::Two => ("viginti", "ms"),
+            Symbol::Three => ("triginta", "ns"),
+            Symbol::Four => ("quadraginta", "ns"),
+            Symbol::Five => ("quinquaginta", "ns"),
+            Symbol::Six => ("sexaginta", "n"),
+            Symbol::Seven => ("septuaginta", "n"),
+            Symbol::Eight => ("octoginta", "mx"),
+            Symbol::Nine => ("nonaginta", ""),
+        }
+    }
+
+    fn latin_hundreds(&self) -> (&'static str, &'static str) {
+        match self {
+            Symbol::Zero => ("", ""),
+            Symbol::One => ("centi", "nx"),
+            Symbol::Two => ("ducenti", "n"),
+            Symbol::Three => ("trecenti", "ns"),
+            Symbol::Four => ("quadringenti", "ns"),
+            Symbol::Five => ("quingenti", "ns"),
+            Symbol::Six => ("sescenti", "n"),
+            Symbol::Seven => ("septingenti", "n"),
+            Symbol::Eight => ("octingenti", "mx"),
+            Symbol::Nine => ("nongenti", ""),
+        }
+    }
+}
+
+//splits into groups of three digits, hundreds first, least significant group first
+fn digit_groups(digits: &str) -> Vec<[Symbol; 3]> {
+    let symbols = digits
+        .chars()
+        .rev()
+        .map(|c| Symbol::from_char(&c).unwrap())
+        .collect::<Vec<_>>();
+    symbols
+        .chunks(3)
+        .map(|chunk| {
+            let digit = |i: usize| chunk.get(i).cloned().unwrap_or(Symbol::Zero);
+            [digit(2), digit(1), digit(0)]
+        })
+        .collect()
+}
+
+//the stem of one group of the index, so 1 gives m and 23 gives tresvigint
+fn latin_stem([hundreds, tens, units]: &[Symbol; 3]) -> String {
+    if *hundreds == Symbol::Zero && *tens == Symbol::Zero {
+        units.latin_small().to_string()
+    } else {
+        let (tens, tens_marks) = tens.latin_tens();
+        let (hundreds, hundreds_marks) = hundreds.latin_hundreds();
+        //the units take their letter from whichever comes straight after them
+        let marks = if tens.is_empty() {
+            hundreds_marks
+        } else {
+            tens_marks
+        };
+        let mut stem = units.latin_units(marks) + tens + hundreds;
+        stem.pop(); //the final vowel gives way to illi
+        stem
+    }
+}
+
+//name of the index'th power of a million without its ending, so 2 gives billi
+//and 1000 gives millinilli, where each group of the index gets its own illi
+fn illi(index: usize) -> String {
+    digit_groups(&index.to_string())
+        .iter()
+        .rev()
+        .map(|group| latin_stem(group) + "illi")
+        .collect()
+}
+
+//spells out an UnsignedInteger in English through Display
+#[derive(Debug)]
+pub struct Words<'a> {
+    value: &'a UnsignedInteger,
+    scale: Scale,
+    dialect: Dialect,
+}
+
+impl UnsignedInteger {
+    pub fn words(&self) -> Words<'_> {
+        Words {
+            value: self,
+            scale: Scale::Short,
+            dialect: Dialect::British,
+        }
+    }
+}
+
+impl Words<'_> {
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    fn british(&self) -> bool {
+        matches!(self.dialect, Dialect::British)
+    }
+
+    //name of the nth group of three digits, counting from the units
+    fn group_name(&self, n: usize) -> Option<String> {
+        match (n, &self.scale) {
+            (0, _) => None,
+            (1, _) => Some("thousand".to_string()),
+            (n, Scale::Short) => Some(illi(n - 1) + "on"),
+            (n, Scale::Long) if n % 2 == 0 => Some(illi(n / 2) + "on"),
+            (n, Scale::Long) => Some(illi(n / 2) + "ard"),
+        }
+    }
+
+    fn group_words(&self, [hundreds, tens, units]: &[Symbol; 3], words: &mut Vec<String>) {
+        if *hundreds != Symbol::Zero {
+            words.push(hundreds.word().to_string());
+            words.push("hundred".to_string());
+        }
+        if *tens != Symbol::Zero || *units != Symbol::Zero {
+            if *hundreds != Symbol::Zero && self.british() {
+                words.push("and".to_string());
+            }
+            words.push(match tens {
+                Symbol::Zero => units.word().to_string(),
+                Symbol::One => units.teen_word().to_string(),
+                tens if *units == Symbol::Zero => tens.tens_word().to_string(),
+                tens => format!("{}-{}", tens.tens_word(), units.word()),
+            });
+        }
+    }
+
+    fn to_words(&self) -> String {
+        if self.value.is_zero() {
+            return Symbol::Zero.word().to_string();
+        }
+        let groups = digit_groups(&self.value.to_decimal_string());
+        let mut words = Vec::new();
+        for (n, group) in groups.iter().enumerate().rev() {
+            if group.iter().all(|digit| *digit == Symbol::Zero) {
+                continue;
+            }
+            //a last group below a hundred still gets its and, as in one thousand and five
+            if n == 0 && !words.is_empty() && group[0] == Symbol::Zero && self.british() {
+                words.push("and".to_string());
+            }
+            self.group_words(group, &mut words);
+            words.extend(self.group_name(n));
+        }
+        words.join(" ")
+    }
+}
+
+impl fmt::Display for Words<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.to_words())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn power_of_ten(n: usize) -> UnsignedInteger {
+        UnsignedInteger::from_str("1").unwrap().shift_digits_up(n)
+    }
+
+    #[test]
+    fn test_words_british() {
+        [
+            ("0", "zero"),
+            ("7", "seven"),
+            ("10", "ten"),
+            ("13", "thirteen"),
+            ("40", "forty"),
+            ("42", "forty-two"),
+            ("100", "one hundred"),
+            ("105", "one hundred and five"),
+            ("999", "nine hundred and ninety-nine"),
+            ("1000", "one thousand"),
+            ("1005", "one thousand and five"),
+            ("1205", "one thousand two hundred and five"),
+            ("1000020", "one million and twenty"),
+            ("1000100", "one million one hundred"),
+            (
+                "2000000300",
+                "two billion three hundred",
+            ),
+            (
+                "123456789",
+                "one hundred and twenty-three million four hundred and fifty-six thousand seven hundred and eighty-nine",
+            ),
+        ]
+        .iter()
+        .for_each(|(x, expected)| {
+            assert_eq!(
+                format!("{}", UnsignedInteger::from_str(x).unwrap().words()),
+                *expected
+            )
+        });
+    }
+    #[test]
+    fn test_words_american() {
+        [
+            ("105", "one hundred five"),
+            ("1005", "one thousand five"),
+            ("1205", "one thousand two hundred five"),
+            (
+                "18446744073709551615",
+                "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four trillion seventy-three billion seven hundred nine million five hundred fifty-one thousand six hundred fifteen",
+            ),
+        ]
+        .iter()
+        .for_each(|(x, expected)| {
+            let x = UnsignedInteger::from_str(x).unwrap();
+            assert_eq!(format!("{}", x.words().dialect(Dialect::American)), *expected)
+        });
+    }
+    #[test]
+    fn test_short_scale_names() {
+        [
+            (6, "million"),
+            (9, "billion"),
+            (30, "nonillion"),
+            (33, "decillion"),
+            (36, "undecillion"),
+            (42, "tredecillion"),
+            (51, "sedecillion"),
+            (54, "septendecillion"),
+            (60, "novendecillion"),
+            (63, "vigintillion"),
+            (72, "tresvigintillion"),
+            (81, "sesvigintillion"),
+            (84, "septemvigintillion"),
+            (90, "novemvigintillion"),
+            (93, "trigintillion"),
+            (243, "octogintillion"),
+            (267, "octooctogintillion"),
+            (303, "centillion"),
+            (306, "uncentillion"),
+            (312, "trescentillion"),
+            (321, "sexcentillion"),
+            (603, "ducentillion"),
+            (3003, "millinillion"),
+            (3006, "millimillion"),
+            (3000003, "millinillinillion"),
+        ]
+        .iter()
+        .for_each(|(n, name)| {
+            assert_eq!(
+                format!("{}", power_of_ten(*n).words()),
+                format!("one {}", name)
+            )
+        });
+    }
+    #[test]
+    fn test_long_scale_names() {
+        [
+            (3, "thousand"),
+            (6, "million"),
+            (9, "milliard"),
+            (12, "billion"),
+            (15, "billiard"),
+            (18, "trillion"),
+            (60, "decillion"),
+            (63, "decilliard"),
+            (600, "centillion"),
+        ]
+        .iter()
+        .for_each(|(n, name)| {
+            assert_eq!(
+                format!("{}", power_of_ten(*n).words().scale(Scale::Long)),
+                format!("one {}", name)
+            )
+        });
+        let x = UnsignedInteger::from_str("2500000000").unwrap();
+        assert_eq!(
+            format!("{}", x.words().scale(Scale::Long)),
+            "two milliard five hundred million"
+        );
+    }
+    #[test]
+    fn test_words_formatter_flags() {
+        let x = UnsignedInteger::from_str("21").unwrap();
+        assert_eq!(format!("{:>12}", x.words()), "  twenty-one");
+        assert_eq!(format!("{:_<11}", x.words()), "twenty-one_");
+    }
+}
@@ -1,5 +1,3 @@
-mod decimal;
-
 fn main() {
     {
     use std::str::FromStr;
@@ -8,7 +6,7 @@ fn main() {
 
     let out_str = x
         .and_then(|a| y.map(|b| (a, b)))
-        .map(|(a, b)| format!(" {}\n+{}\n====================================================================================================================================\n {}", a.clone(), b.clone(), a + b))
+        .map(|(a, b)| format!(" {}\n+{}\n====================================================================================================================================\n {}", a, b, &a + &b))
         .unwrap_or(String::new());
     println!("{}", out_str);
 }
@@ -19,7 +17,7 @@ let y = decimal::UnsignedInteger::from_str("155132430387677124188434311325959960
 
 let out_str = x
     .and_then(|a| y.map(|b| (a, b)))
-    .map(|(a, b)| format!(" {}\n+{}\n====================================================================================================================================\n {}", a.clone(), b.clone(), a + b))
+    .map(|(a, b)| format!(" {}\n+{}\n====================================================================================================================================\n {}", a, b, &a + &b))
     .unwrap_or(String::new());
 println!("{}", out_str);
 }